@@ -0,0 +1,200 @@
+// Async Prover: A proof system running on a server may want to interleave
+// several long computations (hashing, FFTs, commitments) on one thread.
+// This exercise builds the smallest possible async runtime by hand, using
+// only std::future::Future and a manually written Waker, so you can see what
+// crates like Tokio do for you.
+
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+// Exercise 1 Solution: A waker that does nothing
+// Our executor re-polls every pending task in turn, so nobody needs to be
+// woken up. A real runtime would use the waker to reschedule the task.
+fn noop_raw_waker() -> RawWaker {
+    fn clone(_: *const ()) -> RawWaker {
+        noop_raw_waker()
+    }
+    fn noop(_: *const ()) {}
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    RawWaker::new(std::ptr::null(), &VTABLE)
+}
+
+fn noop_waker() -> Waker {
+    // SAFETY: every function in the vtable ignores the data pointer, so
+    // the contract of RawWaker is trivially upheld.
+    unsafe { Waker::from_raw(noop_raw_waker()) }
+}
+
+// Exercise 2 Solution: A single-threaded executor
+struct Executor {
+    queue: VecDeque<Pin<Box<dyn Future<Output = ()>>>>,
+}
+
+impl Executor {
+    fn new() -> Self {
+        Executor {
+            queue: VecDeque::new(),
+        }
+    }
+
+    fn spawn(&mut self, f: impl Future<Output = ()> + 'static) {
+        self.queue.push_back(Box::pin(f));
+    }
+
+    // Poll tasks round-robin. A task that is not finished goes to the back
+    // of the queue so the others get a turn.
+    fn run_until_empty(&mut self) {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        while let Some(mut task) = self.queue.pop_front() {
+            if task.as_mut().poll(&mut cx).is_pending() {
+                self.queue.push_back(task);
+            }
+        }
+    }
+}
+
+// Exercise 3 Solution: Cooperative yielding
+// The future is pending the first time it is polled and ready the second
+// time, which hands control back to the executor exactly once.
+struct YieldPoint {
+    yielded: bool,
+}
+
+impl Future for YieldPoint {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+fn yield_point() -> YieldPoint {
+    YieldPoint { yielded: false }
+}
+
+// Helper function standing in for SHA-256 (not cryptographically secure)
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    for (i, chunk) in output.chunks_mut(8).enumerate() {
+        let mut hasher = DefaultHasher::new();
+        i.hash(&mut hasher);
+        left.hash(&mut hasher);
+        right.hash(&mut hasher);
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    output
+}
+
+fn merkle_root_blocking(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+    }
+    level[0]
+}
+
+// Exercise 4 Solution: An async Merkle root
+// After every level we yield, so other proofs can make progress while
+// this one is still hashing.
+async fn compute_merkle_root(leaves: Vec<[u8; 32]>) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+    let mut level = leaves;
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+        yield_point().await;
+    }
+    level[0]
+}
+
+fn make_leaves(n: usize, salt: u8) -> Vec<[u8; 32]> {
+    (0..n)
+        .map(|i| {
+            let mut leaf = [salt; 32];
+            leaf[..8].copy_from_slice(&(i as u64).to_le_bytes());
+            leaf
+        })
+        .collect()
+}
+
+fn main() {
+    // Test Exercise 2: every spawned task runs to completion
+    let mut executor = Executor::new();
+    let finished = Rc::new(RefCell::new(Vec::new()));
+
+    for id in 0..5 {
+        let finished = Rc::clone(&finished);
+        executor.spawn(async move {
+            finished.borrow_mut().push(id);
+        });
+    }
+    executor.run_until_empty();
+    assert_eq!(*finished.borrow(), vec![0, 1, 2, 3, 4]);
+    assert!(executor.queue.is_empty());
+
+    // Test Exercise 3: tasks that yield are interleaved
+    let log = Rc::new(RefCell::new(Vec::new()));
+    for name in ["a", "b"] {
+        let log = Rc::clone(&log);
+        executor.spawn(async move {
+            for step in 0..3 {
+                log.borrow_mut().push(format!("{}{}", name, step));
+                yield_point().await;
+            }
+        });
+    }
+    executor.run_until_empty();
+    assert_eq!(*log.borrow(), vec!["a0", "b0", "a1", "b1", "a2", "b2"]);
+
+    // Test Exercise 4: async roots match the blocking computation
+    let roots = Rc::new(RefCell::new(Vec::new()));
+    let inputs: Vec<Vec<[u8; 32]>> = (0..3).map(|i| make_leaves(8 << i, i as u8)).collect();
+
+    for (i, leaves) in inputs.iter().cloned().enumerate() {
+        let roots = Rc::clone(&roots);
+        executor.spawn(async move {
+            let root = compute_merkle_root(leaves).await;
+            roots.borrow_mut().push((i, root));
+        });
+    }
+    executor.run_until_empty();
+
+    let mut roots = roots.borrow().clone();
+    assert_eq!(roots.len(), 3);
+    // Smaller trees have fewer levels, so they finish first
+    assert_eq!(
+        roots.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    roots.sort();
+    for (i, root) in roots {
+        assert_eq!(root, merkle_root_blocking(&inputs[i]));
+    }
+
+    println!("All async prover exercises passed!");
+}
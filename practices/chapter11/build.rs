@@ -0,0 +1,8 @@
+fn main() {
+    // Compile c_stubs/sha256_stub.c into a static library and link it
+    cc::Build::new()
+        .file("c_stubs/sha256_stub.c")
+        .compile("sha256_stub");
+
+    println!("cargo:rerun-if-changed=c_stubs/sha256_stub.c");
+}
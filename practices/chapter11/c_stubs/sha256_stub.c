@@ -0,0 +1,16 @@
+#include <stddef.h>
+#include <stdint.h>
+
+/*
+ * A stand-in for a real C SHA-256 implementation such as OpenSSL's.
+ *
+ * It has the same shape as the real thing (input pointer, input length,
+ * 32-byte output buffer) but simply copies the first 32 input bytes and
+ * zero-fills the rest. This keeps the exercise focused on the FFI
+ * boundary instead of the hash itself.
+ */
+void sha256_c(const uint8_t *input, size_t len, uint8_t *output) {
+    for (size_t i = 0; i < 32; i++) {
+        output[i] = i < len ? input[i] : 0;
+    }
+}
@@ -0,0 +1,55 @@
+// FFI Integration: Call a C function from Rust and wrap it in a safe API.
+// Real ZK libraries do this to reuse optimized C cryptography. The C side
+// lives in c_stubs/sha256_stub.c and is compiled by build.rs using the
+// `cc` crate, so `cargo run --bin ffi` builds and links it automatically.
+
+// Exercise 1 Solution: Declare the foreign function
+// The signature must match the C prototype exactly:
+//   void sha256_c(const uint8_t *input, size_t len, uint8_t *output);
+extern "C" {
+    fn sha256_c(input: *const u8, len: usize, output: *mut u8);
+}
+
+// Exercise 2 Solution: A safe wrapper
+// All the unsafe reasoning is kept in one place, so callers never deal
+// with raw pointers.
+fn sha256_via_ffi(data: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    // SAFETY: `data` is a valid slice of `data.len()` bytes, and `output`
+    // is a writable 32-byte buffer, which is all sha256_c reads and writes.
+    unsafe {
+        sha256_c(data.as_ptr(), data.len(), output.as_mut_ptr());
+    }
+    output
+}
+
+// Exercise 3 Solution: The same function in pure Rust
+// The stub is not a real hash, and neither is this: it mirrors what the
+// stub does, copying the first 32 bytes and zero-filling the rest. Swapping
+// both sides for a real SHA-256 keeps the comparison below meaningful.
+fn stub_reference(data: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let n = data.len().min(32);
+    output[..n].copy_from_slice(&data[..n]);
+    output
+}
+
+fn main() {
+    // Test Exercise 2 and 3: the FFI call and the Rust reference agree
+    let inputs: [&[u8]; 4] = [
+        b"",
+        b"halo2",
+        b"exactly thirty-two bytes long!!!",
+        b"a message that is longer than thirty-two bytes gets truncated",
+    ];
+
+    for input in inputs {
+        assert_eq!(sha256_via_ffi(input), stub_reference(input));
+    }
+
+    let digest = sha256_via_ffi(b"halo2");
+    assert_eq!(&digest[..5], b"halo2");
+    assert!(digest[5..].iter().all(|&b| b == 0));
+
+    println!("FFI digest of \"halo2\": {:02x?}", digest);
+}
@@ -6,11 +6,92 @@
 // 4. Handle invalid inputs
 // 5. Generate reports
 
+// Bonus: Plus/minus grading, e.g. A+, A, A-
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PreciseGrade {
+    APlus,
+    A,
+    AMinus,
+    BPlus,
+    B,
+    BMinus,
+    CPlus,
+    C,
+    CMinus,
+    DPlus,
+    D,
+    F,
+}
+
+impl PreciseGrade {
+    fn to_gpa_points(self) -> f64 {
+        match self {
+            PreciseGrade::APlus | PreciseGrade::A => 4.0,
+            PreciseGrade::AMinus => 3.7,
+            PreciseGrade::BPlus => 3.3,
+            PreciseGrade::B => 3.0,
+            PreciseGrade::BMinus => 2.7,
+            PreciseGrade::CPlus => 2.3,
+            PreciseGrade::C => 2.0,
+            PreciseGrade::CMinus => 1.7,
+            PreciseGrade::DPlus => 1.3,
+            PreciseGrade::D => 1.0,
+            PreciseGrade::F => 0.0,
+        }
+    }
+
+    fn to_letter(self) -> char {
+        match self {
+            PreciseGrade::APlus | PreciseGrade::A | PreciseGrade::AMinus => 'A',
+            PreciseGrade::BPlus | PreciseGrade::B | PreciseGrade::BMinus => 'B',
+            PreciseGrade::CPlus | PreciseGrade::C | PreciseGrade::CMinus => 'C',
+            PreciseGrade::DPlus | PreciseGrade::D => 'D',
+            PreciseGrade::F => 'F',
+        }
+    }
+
+    fn to_full_string(self) -> &'static str {
+        match self {
+            PreciseGrade::APlus => "A+",
+            PreciseGrade::A => "A",
+            PreciseGrade::AMinus => "A-",
+            PreciseGrade::BPlus => "B+",
+            PreciseGrade::B => "B",
+            PreciseGrade::BMinus => "B-",
+            PreciseGrade::CPlus => "C+",
+            PreciseGrade::C => "C",
+            PreciseGrade::CMinus => "C-",
+            PreciseGrade::DPlus => "D+",
+            PreciseGrade::D => "D",
+            PreciseGrade::F => "F",
+        }
+    }
+}
+
+fn calculate_precise_grade(average: f64) -> PreciseGrade {
+    match average {
+        avg if avg >= 97.0 => PreciseGrade::APlus,
+        avg if avg >= 93.0 => PreciseGrade::A,
+        avg if avg >= 90.0 => PreciseGrade::AMinus,
+        avg if avg >= 87.0 => PreciseGrade::BPlus,
+        avg if avg >= 83.0 => PreciseGrade::B,
+        avg if avg >= 80.0 => PreciseGrade::BMinus,
+        avg if avg >= 77.0 => PreciseGrade::CPlus,
+        avg if avg >= 73.0 => PreciseGrade::C,
+        avg if avg >= 70.0 => PreciseGrade::CMinus,
+        avg if avg >= 67.0 => PreciseGrade::DPlus,
+        avg if avg >= 60.0 => PreciseGrade::D,
+        _ => PreciseGrade::F,
+    }
+}
+
 #[derive(Debug)]
 struct Student {
     name: String,
     grades: Vec<f64>,
     letter_grade: Option<char>,
+    use_precise_grading: bool,
+    precise_grade: Option<PreciseGrade>,
 }
 
 impl Student {
@@ -19,11 +100,18 @@ impl Student {
             name,
             grades: Vec::new(),
             letter_grade: None,
+            use_precise_grading: false,
+            precise_grade: None,
         }
     }
 
+    fn enable_precise_grading(&mut self) {
+        self.use_precise_grading = true;
+        self.calculate_letter_grade();
+    }
+
     fn add_grade(&mut self, grade: f64) -> Result<(), String> {
-        if grade < 0.0 || grade > 100.0 {
+        if !(0.0..=100.0).contains(&grade) {
             return Err("Grade must be between 0 and 100".to_string());
         }
         self.grades.push(grade);
@@ -40,6 +128,12 @@ impl Student {
     }
 
     fn calculate_letter_grade(&mut self) {
+        if self.use_precise_grading {
+            self.precise_grade = self.calculate_average().map(calculate_precise_grade);
+            self.letter_grade = self.precise_grade.map(|grade| grade.to_letter());
+            return;
+        }
+
         self.letter_grade = self.calculate_average().map(|avg| match avg {
             avg if avg >= 90.0 => 'A',
             avg if avg >= 80.0 => 'B',
@@ -55,10 +149,13 @@ impl Student {
             .map(|a| a.to_string())
             .unwrap_or("No grades yet".to_string());
 
-        let letter = self
-            .letter_grade
-            .map(|l| l.to_string())
-            .unwrap_or("N/A".to_string());
+        let letter = match self.precise_grade {
+            Some(grade) if self.use_precise_grading => grade.to_full_string().to_string(),
+            _ => self
+                .letter_grade
+                .map(|l| l.to_string())
+                .unwrap_or("N/A".to_string()),
+        };
 
         format!(
             "Student: {}\nGrades: {:?}\nAverage: {}\nLetter Grade: {}",
@@ -89,8 +186,52 @@ fn main() {
         eprintln!("Error: {}", e);
     }
 
+    // Bonus: Carol uses plus/minus grading
+    let mut carol = Student::new("Carol".to_string());
+    carol.enable_precise_grading();
+    if let Err(e) = carol.add_grade(91.0) {
+        eprintln!("Error: {}", e);
+    }
+    if let Err(e) = carol.add_grade(88.0) {
+        eprintln!("Error: {}", e);
+    }
+    assert_eq!(carol.precise_grade, Some(PreciseGrade::BPlus));
+    assert_eq!(carol.letter_grade, Some('B'));
+    students.push(carol);
+
     // Generate reports
     for student in &students {
         println!("\n{}", student.generate_report());
     }
+
+    // Test the plus/minus cutoffs: each grade level and its boundary
+    let levels = [
+        (97.0, PreciseGrade::APlus, "A+", 'A'),
+        (93.0, PreciseGrade::A, "A", 'A'),
+        (90.0, PreciseGrade::AMinus, "A-", 'A'),
+        (87.0, PreciseGrade::BPlus, "B+", 'B'),
+        (83.0, PreciseGrade::B, "B", 'B'),
+        (80.0, PreciseGrade::BMinus, "B-", 'B'),
+        (77.0, PreciseGrade::CPlus, "C+", 'C'),
+        (73.0, PreciseGrade::C, "C", 'C'),
+        (70.0, PreciseGrade::CMinus, "C-", 'C'),
+        (67.0, PreciseGrade::DPlus, "D+", 'D'),
+        (60.0, PreciseGrade::D, "D", 'D'),
+        (0.0, PreciseGrade::F, "F", 'F'),
+    ];
+    for (cutoff, grade, full, letter) in levels {
+        assert_eq!(calculate_precise_grade(cutoff), grade);
+        assert_eq!(grade.to_full_string(), full);
+        assert_eq!(grade.to_letter(), letter);
+        if cutoff > 0.0 {
+            // Just below a cutoff falls into the next level down
+            assert_ne!(calculate_precise_grade(cutoff - 0.01), grade);
+        }
+    }
+    assert_eq!(calculate_precise_grade(100.0), PreciseGrade::APlus);
+
+    // GPA points never go up as the grade goes down
+    for pair in levels.windows(2) {
+        assert!(pair[0].1.to_gpa_points() >= pair[1].1.to_gpa_points());
+    }
 }
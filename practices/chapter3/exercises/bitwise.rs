@@ -0,0 +1,239 @@
+// Bitwise Operations: Every bitwise operator in Rust has a matching
+// arithmetic gate in a ZK circuit. Inside a circuit each bit `a` is a field
+// element constrained by `a * (a - 1) = 0`, so it can only be 0 or 1, and
+// then:
+//   AND(a, b) = a * b
+//   OR(a, b)  = a + b - a * b
+//   XOR(a, b) = a + b - 2 * a * b
+//   NOT(a)    = 1 - a
+// Keep these formulas in mind while working through the exercises.
+
+// Exercise 1 Solution: Count set bits
+// In a circuit this is the sum of the bit wires of a decomposed value.
+fn count_set_bits(n: u32) -> u32 {
+    n.count_ones()
+}
+
+// Exercise 2 Solution: Reverse the bits in a byte
+// Circuits get this for free: reversing is just rewiring, no gates needed.
+fn reverse_bits(byte: u8) -> u8 {
+    let mut result = 0u8;
+    for i in 0..8 {
+        if (byte >> i) & 1 == 1 {
+            result |= 1 << (7 - i);
+        }
+    }
+    result
+}
+
+// Exercise 3 Solution: Detect a power of two
+// A power of two has exactly one set bit, and subtracting one flips that
+// bit and sets every bit below it, so the AND of the two is zero.
+fn is_power_of_two(n: u32) -> bool {
+    n != 0 && n & (n - 1) == 0
+}
+
+// Exercise 4 Solution: Bitwise AND without the `&` operator
+// Walk the bits with shifts and multiply them, exactly like the AND gate.
+fn and_without_operator(a: u32, b: u32) -> u32 {
+    let mut result = 0u32;
+    for i in 0..32 {
+        let bit_a = (a >> i) % 2;
+        let bit_b = (b >> i) % 2;
+        result += (bit_a * bit_b) << i;
+    }
+    result
+}
+
+// Exercise 5 Solution: XOR of a value with itself is zero
+// With the gate formula: a + a - 2 * a * a = 2a - 2a = 0 for a in {0, 1}.
+#[allow(clippy::eq_op)] // XOR-ing a value with itself is the whole point here
+fn xor_with_self(n: u64) -> u64 {
+    n ^ n
+}
+
+// Exercise 6 Solution: A packed bit vector
+// Like a circuit, we pack many boolean "wires" side by side, but here 64
+// of them share one machine word, so a single instruction acts on all.
+#[derive(Debug, Clone, PartialEq)]
+struct BitVec {
+    data: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    fn new(len: usize) -> BitVec {
+        BitVec {
+            data: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    fn from_bools(bits: &[bool]) -> BitVec {
+        let mut bv = BitVec::new(bits.len());
+        for (i, &bit) in bits.iter().enumerate() {
+            bv.set(i, bit);
+        }
+        bv
+    }
+
+    fn to_bools(&self) -> Vec<bool> {
+        (0..self.len).map(|i| self.get(i)).collect()
+    }
+
+    fn get(&self, i: usize) -> bool {
+        assert!(
+            i < self.len,
+            "index {} out of bounds for length {}",
+            i,
+            self.len
+        );
+        (self.data[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn set(&mut self, i: usize, val: bool) {
+        assert!(
+            i < self.len,
+            "index {} out of bounds for length {}",
+            i,
+            self.len
+        );
+        if val {
+            self.data[i / 64] |= 1 << (i % 64);
+        } else {
+            self.data[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    fn zip_with(&self, other: &BitVec, op: impl Fn(u64, u64) -> u64) -> BitVec {
+        assert_eq!(self.len, other.len, "bit vectors must have the same length");
+        BitVec {
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+            len: self.len,
+        }
+    }
+
+    // Circuit gate: a * b
+    fn and(&self, other: &BitVec) -> BitVec {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    // Circuit gate: a + b - a * b
+    fn or(&self, other: &BitVec) -> BitVec {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    // Circuit gate: a + b - 2 * a * b
+    fn xor(&self, other: &BitVec) -> BitVec {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    // Circuit gate: 1 - a
+    // The unused bits of the last word must stay zero, or count_ones
+    // would count bits that are not part of the vector.
+    fn not(&self) -> BitVec {
+        let mut result = BitVec {
+            data: self.data.iter().map(|&word| !word).collect(),
+            len: self.len,
+        };
+        let tail = self.len % 64;
+        if tail != 0 {
+            if let Some(last) = result.data.last_mut() {
+                *last &= (1u64 << tail) - 1;
+            }
+        }
+        result
+    }
+
+    fn count_ones(&self) -> usize {
+        self.data
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+}
+
+fn main() {
+    // Test Exercise 1
+    assert_eq!(count_set_bits(0), 0);
+    assert_eq!(count_set_bits(0b1011), 3);
+    assert_eq!(count_set_bits(u32::MAX), 32);
+
+    // Test Exercise 2
+    assert_eq!(reverse_bits(0b0000_0001), 0b1000_0000);
+    assert_eq!(reverse_bits(0b1100_1010), 0b0101_0011);
+    for byte in 0..=255u8 {
+        assert_eq!(reverse_bits(byte), byte.reverse_bits());
+    }
+
+    // Test Exercise 3
+    assert!(is_power_of_two(1));
+    assert!(is_power_of_two(1024));
+    assert!(!is_power_of_two(0));
+    assert!(!is_power_of_two(12));
+    for n in 0..5000u32 {
+        assert_eq!(is_power_of_two(n), n.is_power_of_two());
+    }
+
+    // Test Exercise 4
+    assert_eq!(and_without_operator(0b1100, 0b1010), 0b1000);
+    assert_eq!(and_without_operator(u32::MAX, 12345), 12345);
+    assert_eq!(
+        and_without_operator(0xDEAD_BEEF, 0x0F0F_0F0F),
+        0xDEAD_BEEF & 0x0F0F_0F0F
+    );
+
+    // Test Exercise 5
+    for n in [0, 1, 42, u64::MAX] {
+        assert_eq!(xor_with_self(n), 0);
+    }
+
+    // Test Exercise 6: every operation against its Vec<bool> equivalent.
+    // 130 bits spans three words, so the partial last word is covered.
+    let len = 130;
+    let a_bools: Vec<bool> = (0..len).map(|i| i % 3 == 0).collect();
+    let b_bools: Vec<bool> = (0..len).map(|i| i % 5 < 2).collect();
+    let a = BitVec::from_bools(&a_bools);
+    let b = BitVec::from_bools(&b_bools);
+
+    assert_eq!(a.to_bools(), a_bools);
+
+    let and: Vec<bool> = a_bools
+        .iter()
+        .zip(&b_bools)
+        .map(|(&x, &y)| x && y)
+        .collect();
+    let or: Vec<bool> = a_bools
+        .iter()
+        .zip(&b_bools)
+        .map(|(&x, &y)| x || y)
+        .collect();
+    let xor: Vec<bool> = a_bools
+        .iter()
+        .zip(&b_bools)
+        .map(|(&x, &y)| x != y)
+        .collect();
+    let not: Vec<bool> = a_bools.iter().map(|&x| !x).collect();
+
+    assert_eq!(a.and(&b).to_bools(), and);
+    assert_eq!(a.or(&b).to_bools(), or);
+    assert_eq!(a.xor(&b).to_bools(), xor);
+    assert_eq!(a.not().to_bools(), not);
+
+    assert_eq!(a.count_ones(), a_bools.iter().filter(|&&x| x).count());
+    assert_eq!(a.not().count_ones(), not.iter().filter(|&&x| x).count());
+    assert_eq!(a.xor(&a).count_ones(), 0);
+
+    let mut c = BitVec::new(len);
+    c.set(129, true);
+    assert!(c.get(129));
+    c.set(129, false);
+    assert!(!c.get(129));
+
+    println!("All bitwise exercises passed!");
+}
@@ -0,0 +1,160 @@
+// Custom Iterators: Rebuild a few standard iterator adaptors from scratch.
+// Each adaptor is a struct that owns the iterator it wraps and implements
+// `Iterator` by pulling items out of it.
+
+use std::iter::Peekable;
+
+// Exercise 1 Solution: NumberedIterator, our own `enumerate()`
+struct NumberedIterator<I: Iterator> {
+    inner: I,
+    index: usize,
+}
+
+impl<I: Iterator> Iterator for NumberedIterator<I> {
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some((index, item))
+    }
+}
+
+fn numbered<I: Iterator>(iter: I) -> NumberedIterator<I> {
+    NumberedIterator {
+        inner: iter,
+        index: 0,
+    }
+}
+
+// Exercise 2 Solution: PeekableBuffer, our own `peekable()`
+// Peeking pulls the next item early and keeps it in the buffer until
+// `next` hands it out. The buffer is an Option<Option<_>>, like std's:
+// the outer layer says whether we have peeked, the inner one is what we
+// saw. A peeked `None` has to be remembered too, otherwise an iterator
+// that isn't fused would be asked again and could return something else.
+struct PeekableBuffer<I: Iterator> {
+    inner: I,
+    buffer: Option<Option<I::Item>>,
+}
+
+impl<I: Iterator> PeekableBuffer<I> {
+    fn new(inner: I) -> Self {
+        PeekableBuffer {
+            inner,
+            buffer: None,
+        }
+    }
+
+    fn peek(&mut self) -> Option<&I::Item> {
+        let inner = &mut self.inner;
+        self.buffer.get_or_insert_with(|| inner.next()).as_ref()
+    }
+}
+
+impl<I: Iterator> Iterator for PeekableBuffer<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.buffer.take() {
+            Some(peeked) => peeked,
+            None => self.inner.next(),
+        }
+    }
+}
+
+// Exercise 3 Solution: Chunked, like `slice::chunks()` but for any iterator
+// The last chunk may be shorter than `size`.
+struct Chunked<I: Iterator> {
+    inner: Peekable<I>,
+    size: usize,
+}
+
+impl<I: Iterator> Chunked<I> {
+    fn new(inner: I, size: usize) -> Self {
+        assert!(size > 0, "chunk size must be greater than zero");
+        Chunked {
+            inner: inner.peekable(),
+            size,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Chunked<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.peek()?;
+        let chunk: Vec<I::Item> = self.inner.by_ref().take(self.size).collect();
+        Some(chunk)
+    }
+}
+
+fn main() {
+    let words = vec!["halo2", "plonk", "kzg", "ipa", "fri"];
+
+    // Test Exercise 1 against enumerate()
+    let ours: Vec<(usize, &&str)> = numbered(words.iter()).collect();
+    let std: Vec<(usize, &&str)> = words.iter().enumerate().collect();
+    assert_eq!(ours, std);
+    assert_eq!(numbered(std::iter::empty::<i32>()).count(), 0);
+
+    // Test Exercise 2 against peekable()
+    let mut ours = PeekableBuffer::new(1..=3);
+    let mut std = (1..=3).peekable();
+    assert_eq!(ours.peek(), std.peek());
+    assert_eq!(ours.peek(), Some(&1)); // peeking twice doesn't advance
+    loop {
+        let (a, b) = (ours.next(), std.next());
+        assert_eq!(a, b);
+        assert_eq!(ours.peek(), std.peek());
+        if a.is_none() {
+            break;
+        }
+    }
+
+    // An iterator that isn't fused: it returns None on every other call.
+    // A peeked None must be handed out by next() before asking again.
+    struct Flaky(u32);
+    impl Iterator for Flaky {
+        type Item = u32;
+        fn next(&mut self) -> Option<u32> {
+            self.0 += 1;
+            self.0.is_multiple_of(2).then_some(self.0)
+        }
+    }
+    let mut ours = PeekableBuffer::new(Flaky(0));
+    let mut std = Flaky(0).peekable();
+    assert_eq!(ours.peek(), None);
+    assert_eq!(ours.peek(), std.peek());
+    assert_eq!(ours.peek(), std.peek()); // still None, inner not called again
+    for _ in 0..3 {
+        assert_eq!(ours.next(), std.next());
+        assert_eq!(ours.peek(), std.peek());
+        assert_eq!(ours.next(), std.next());
+    }
+    assert_eq!(ours.next(), std.next());
+
+    // Test Exercise 3 against slice::chunks()
+    let numbers: Vec<i32> = (1..=10).collect();
+    for size in 1..=12 {
+        let ours: Vec<Vec<i32>> = Chunked::new(numbers.iter().copied(), size).collect();
+        let std: Vec<Vec<i32>> = numbers.chunks(size).map(|c| c.to_vec()).collect();
+        assert_eq!(ours, std);
+    }
+    assert_eq!(Chunked::new(std::iter::empty::<i32>(), 3).count(), 0);
+
+    // Adaptors compose like the standard ones
+    let paired: Vec<(usize, Vec<&str>)> = numbered(Chunked::new(words.into_iter(), 2)).collect();
+    assert_eq!(
+        paired,
+        vec![
+            (0, vec!["halo2", "plonk"]),
+            (1, vec!["kzg", "ipa"]),
+            (2, vec!["fri"]),
+        ]
+    );
+
+    println!("All custom iterator exercises passed!");
+}
@@ -0,0 +1,105 @@
+// Recursion vs Iteration: Four ways to compute Fibonacci numbers, and what
+// each one costs in time and stack space.
+//
+// Why doesn't Rust guarantee tail-call optimization (TCO)?
+// A tail call can only reuse the caller's stack frame if nothing is left to
+// do after the call returns. In Rust there often is: values going out of
+// scope must run their `Drop` code after the call, which keeps the frame
+// alive. Guaranteeing TCO would also have to work across every calling
+// convention and backend Rust supports. So LLVM *may* turn a tail call into
+// a loop in release builds, but nothing promises it, and debug builds
+// usually don't. If stack depth matters, write the loop yourself.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+// Exercise 1 Solution: Simple recursion
+// Each call makes two more calls, so the running time grows like fib(n)
+// itself (roughly 1.6^n). n = 40 already takes about a second, and anything
+// near 85 would never finish. The call depth is only n, but the same shape
+// of recursion over a large input (a long list, a deep tree) is what
+// overflows the stack.
+fn fibonacci_recursive(n: u32) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => fibonacci_recursive(n - 1) + fibonacci_recursive(n - 2),
+    }
+}
+
+// Exercise 2 Solution: Tail recursion
+// The recursive call is the last thing the function does, and the running
+// totals travel along in the arguments. This is linear time, but without
+// guaranteed TCO every call still gets its own stack frame.
+// Call it as fibonacci_tail_recursive(n, 0, 1).
+fn fibonacci_tail_recursive(n: u32, acc1: u64, acc2: u64) -> u64 {
+    if n == 0 {
+        acc1
+    } else {
+        fibonacci_tail_recursive(n - 1, acc2, acc1 + acc2)
+    }
+}
+
+// Exercise 3 Solution: Iteration
+// The tail-recursive version rewritten as a loop: linear time and a
+// constant amount of stack.
+fn fibonacci_iterative(n: u32) -> u64 {
+    let mut a: u64 = 0;
+    let mut b: u64 = 1;
+    for _ in 0..n {
+        let next = a + b;
+        a = b;
+        b = next;
+    }
+    a
+}
+
+// Exercise 4 Solution: Memoization
+// Same recursive shape as Exercise 1, but every value is computed once and
+// then looked up, turning exponential time into linear time.
+fn fibonacci_memoized(n: u32, cache: &mut HashMap<u32, u64>) -> u64 {
+    if n <= 1 {
+        return n as u64;
+    }
+    if let Some(&value) = cache.get(&n) {
+        return value;
+    }
+    let value = fibonacci_memoized(n - 1, cache) + fibonacci_memoized(n - 2, cache);
+    cache.insert(n, value);
+    value
+}
+
+fn main() {
+    // All four versions agree on small inputs
+    for n in 0..25 {
+        let expected = fibonacci_iterative(n);
+        assert_eq!(fibonacci_recursive(n), expected);
+        assert_eq!(fibonacci_tail_recursive(n, 0, 1), expected);
+        assert_eq!(fibonacci_memoized(n, &mut HashMap::new()), expected);
+    }
+
+    // fib(90) fits in a u64 without overflowing
+    assert_eq!(fibonacci_iterative(90), 2880067194370816120);
+    assert_eq!(fibonacci_tail_recursive(90, 0, 1), 2880067194370816120);
+    assert_eq!(
+        fibonacci_memoized(90, &mut HashMap::new()),
+        2880067194370816120
+    );
+
+    // Timing comparisons
+    let start = Instant::now();
+    let value = fibonacci_recursive(30);
+    println!("recursive(30)      = {} in {:?}", value, start.elapsed());
+
+    let start = Instant::now();
+    let value = fibonacci_tail_recursive(90, 0, 1);
+    println!("tail_recursive(90) = {} in {:?}", value, start.elapsed());
+
+    let start = Instant::now();
+    let value = fibonacci_iterative(90);
+    println!("iterative(90)      = {} in {:?}", value, start.elapsed());
+
+    let start = Instant::now();
+    let value = fibonacci_memoized(90, &mut HashMap::new());
+    println!("memoized(90)       = {} in {:?}", value, start.elapsed());
+}
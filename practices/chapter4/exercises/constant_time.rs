@@ -0,0 +1,149 @@
+// Constant-Time Arithmetic: When a value is secret, like a private key or
+// a witness, the time an operation takes must not depend on it. Whatever
+// an attacker can time, they can learn.
+//
+// Why `if secret_flag { ... }` leaks:
+// - The two sides of the branch run different instructions, so they take
+//   different amounts of time, and that shows up over enough runs.
+// - The CPU's branch predictor learns which way the branch usually goes.
+//   A wrong guess costs a pipeline flush of a dozen or more cycles, so the
+//   timing also reveals whether this secret matched the previous ones, and
+//   another process on the same core can probe the predictor's state.
+// - Each side touches different memory, which leaves traces in the cache.
+// The same goes for `%` and `/` on many CPUs, whose division takes longer
+// for some inputs. The functions below use only masks, shifts and
+// wrapping arithmetic, and loop a fixed number of times. The compiler can
+// still turn such code back into branches, which is why real libraries
+// (like the `subtle` crate) add optimization barriers as well.
+
+// Exercise 1 Solution: Comparing and choosing without branching
+// 1 if a == b, otherwise 0. x = a ^ b is zero only when they are equal,
+// and for any non-zero x, x | -x has its top bit set.
+fn ct_eq(a: u64, b: u64) -> u8 {
+    let x = a ^ b;
+    (((x | x.wrapping_neg()) >> 63) ^ 1) as u8
+}
+
+// a if flag is 1, b if flag is 0. `flag` must be 0 or 1: it becomes a mask
+// of all ones or all zeros.
+fn ct_select(a: u64, b: u64, flag: u8) -> u64 {
+    let mask = (flag as u64).wrapping_neg();
+    (a & mask) | (b & !mask)
+}
+
+fn ct_select_u128(a: u128, b: u128, flag: u8) -> u128 {
+    let mask = (flag as u128).wrapping_neg();
+    (a & mask) | (b & !mask)
+}
+
+// Exercise 2 Solution: Reduction without division
+// Schoolbook binary long division: bring down one bit of `a` at a time,
+// and subtract the modulus whenever the remainder has reached it. It runs
+// all 128 steps whatever `a` is, and the "whenever" is a select, not an if.
+fn ct_mod(a: u128, modulus: u64) -> u64 {
+    let m = modulus as u128;
+    let mut r: u128 = 0;
+    for i in (0..128).rev() {
+        // r < m before the shift, so r < 2m < 2^65 after it
+        r = (r << 1) | ((a >> i) & 1);
+        let (reduced, borrowed) = r.overflowing_sub(m);
+        r = ct_select_u128(r, reduced, borrowed as u8);
+    }
+    r as u64
+}
+
+// The product of two u64 values always fits in 128 bits
+fn ct_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    ct_mod(a as u128 * b as u128, modulus)
+}
+
+// Exercise 3 Solution: A constant-time inverse
+// Helper type: just enough of a field element for this exercise
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FieldElement {
+    value: u64,
+    modulus: u64,
+}
+
+impl FieldElement {
+    fn new(value: u64, modulus: u64) -> FieldElement {
+        FieldElement {
+            value: ct_mod(value as u128, modulus),
+            modulus,
+        }
+    }
+
+    // Fermat: a^(p-2) is the inverse of a for a prime p. Square-and-
+    // multiply would normally multiply only on the 1 bits of the exponent;
+    // here every bit does the multiplication and a select keeps it or not,
+    // so all 64 rounds cost the same. Zero comes back as zero instead of
+    // None, so the caller decides what to do without this function
+    // branching on the value.
+    fn inverse(&self) -> FieldElement {
+        let exponent = self.modulus - 2;
+        let mut result = 1;
+        let mut base = self.value;
+        for i in 0..64 {
+            let bit = ((exponent >> i) & 1) as u8;
+            result = ct_select(ct_mul(result, base, self.modulus), result, bit);
+            base = ct_mul(base, base, self.modulus);
+        }
+        FieldElement::new(result, self.modulus)
+    }
+}
+
+fn main() {
+    let big_prime = 18_446_744_073_709_551_557; // largest prime below 2^64
+    let moduli = [
+        2,
+        3,
+        17,
+        65537,
+        (1 << 32) + 15,
+        (1 << 61) - 1,
+        big_prime,
+        u64::MAX,
+    ];
+    let values = [0, 1, 2, 1 << 32, 1 << 63, u64::MAX - 1, u64::MAX];
+
+    // Test Exercise 1: ct_eq and ct_select at the edges
+    for &a in &values {
+        for &b in &values {
+            assert_eq!(ct_eq(a, b), (a == b) as u8);
+            assert_eq!(ct_select(a, b, 1), a);
+            assert_eq!(ct_select(a, b, 0), b);
+        }
+    }
+
+    // Test Exercise 2: ct_mod agrees with % and ct_mul with u128
+    // arithmetic, at the boundaries of every modulus
+    let big = [0, 1, u64::MAX as u128, 1 << 64, u128::MAX - 1, u128::MAX];
+    for &m in &moduli {
+        for &a in &big {
+            assert_eq!(ct_mod(a, m) as u128, a % m as u128);
+        }
+        let edges = [0, 1, m / 2, m - 2, m - 1, m, u64::MAX];
+        for &a in &edges {
+            for &b in &edges {
+                let expected = (a as u128 * b as u128 % m as u128) as u64;
+                assert_eq!(ct_mul(a, b, m), expected);
+            }
+        }
+    }
+    // (p - 1)^2 = 1, computed from a product that needs all 128 bits
+    assert_eq!(ct_mul(big_prime - 1, big_prime - 1, big_prime), 1);
+    println!("ct_mod and ct_mul match the ordinary operators on all edge cases");
+
+    // Test Exercise 3: every non-zero element of GF(17) has an inverse
+    for v in 1..17 {
+        let a = FieldElement::new(v, 17);
+        assert_eq!(ct_mul(a.value, a.inverse().value, 17), 1);
+    }
+    assert_eq!(FieldElement::new(0, 17).inverse().value, 0);
+    for &v in &[2, 12345, big_prime - 1, big_prime - 2] {
+        let a = FieldElement::new(v, big_prime);
+        assert_eq!(ct_mul(a.value, a.inverse().value, big_prime), 1);
+    }
+    let a = FieldElement::new(12345, big_prime);
+    println!("12345^-1 mod {} = {}", big_prime, a.inverse().value);
+}
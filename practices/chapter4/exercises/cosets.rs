@@ -0,0 +1,167 @@
+// Cosets: The non-zero elements mod p form a cyclic group of size p - 1.
+// For every n dividing p - 1 it has exactly one subgroup H of size n, and
+// multiplying H by any element g gives a coset gH. The cosets of H split
+// the group into equal, non-overlapping pieces.
+//
+// Halo2 needs this for its FFT domains. Its evaluation domain is a
+// subgroup H of size 2^k, and the vanishing polynomial Z(X) = X^n - 1 is
+// zero on all of H. To divide by Z when computing the quotient polynomial,
+// halo2 evaluates on a coset gH of a larger domain instead, where Z is
+// never zero.
+
+use std::collections::HashSet;
+
+// Helper functions: modular multiplication in u128 and square-and-multiply
+fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+fn mod_pow(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+// Helper function: the distinct prime factors, by trial division
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            factors.push(d);
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+// Exercise 1 Solution: A generator of the whole group
+// g generates the group when its order is p - 1. That order divides
+// p - 1, so if it were smaller it would divide (p - 1) / q for some prime
+// factor q, and g^((p-1)/q) would be 1. Trying each q is enough.
+fn multiplicative_group_generator(prime: u64) -> u64 {
+    let factors = prime_factors(prime - 1);
+    (1..prime)
+        .find(|&g| {
+            factors
+                .iter()
+                .all(|q| mod_pow(g, (prime - 1) / q, prime) != 1)
+        })
+        .expect("the multiplicative group of a prime field is cyclic")
+}
+
+// Exercise 2 Solution: The subgroup of order n
+// If g generates the group, h = g^((p-1)/n) has order exactly n, and its
+// powers 1, h, h^2, ..., h^(n-1) are the subgroup, listed in the same
+// order an FFT domain uses.
+fn subgroup_of_order(n: usize, prime: u64) -> Vec<u64> {
+    assert!(
+        n > 0 && (prime - 1).is_multiple_of(n as u64),
+        "no subgroup of order {}: it must divide p - 1 = {}",
+        n,
+        prime - 1
+    );
+    let h = mod_pow(
+        multiplicative_group_generator(prime),
+        (prime - 1) / n as u64,
+        prime,
+    );
+    std::iter::successors(Some(1), |&x| Some(mod_mul(x, h, prime)))
+        .take(n)
+        .collect()
+}
+
+// Exercise 3 Solution: Cosets
+fn coset(generator: u64, subgroup: &[u64], prime: u64) -> Vec<u64> {
+    subgroup
+        .iter()
+        .map(|&s| mod_mul(generator, s, prime))
+        .collect()
+}
+
+// Take the smallest element not yet covered and add its coset, until
+// every element is covered. The subgroup itself (the coset of 1) comes
+// first.
+fn all_cosets(prime: u64, subgroup_order: usize) -> Vec<Vec<u64>> {
+    let subgroup = subgroup_of_order(subgroup_order, prime);
+    let mut covered = HashSet::new();
+    let mut cosets = Vec::new();
+    for g in 1..prime {
+        if covered.contains(&g) {
+            continue;
+        }
+        let c = coset(g, &subgroup, prime);
+        covered.extend(c.iter().copied());
+        cosets.push(c);
+    }
+    cosets
+}
+
+fn main() {
+    // Test Exercise 1: 3 generates GF(17)*, 2 doesn't (its order is 8)
+    assert_eq!(multiplicative_group_generator(17), 3);
+    assert_eq!(multiplicative_group_generator(7), 3);
+    assert_eq!(multiplicative_group_generator(97), 5);
+    let g = multiplicative_group_generator(65537);
+    let powers: HashSet<u64> = std::iter::successors(Some(1), |&x| Some(mod_mul(x, g, 65537)))
+        .take(65536)
+        .collect();
+    assert_eq!(powers.len(), 65536);
+    println!("Generator of GF(65537)*: {}", g);
+
+    // Test Exercise 2: the subgroup of order 4 in GF(17)* is {1, 4, 13, 16}
+    let subgroup = subgroup_of_order(4, 17);
+    println!("Subgroup of order 4 mod 17: {:?}", subgroup);
+    assert_eq!(subgroup, vec![1, 13, 16, 4]);
+    assert!(subgroup.iter().all(|&s| mod_pow(s, 4, 17) == 1));
+    // Closed under multiplication, as a subgroup has to be
+    for &a in &subgroup {
+        for &b in &subgroup {
+            assert!(subgroup.contains(&mod_mul(a, b, 17)));
+        }
+    }
+
+    // Test Exercise 3: the cosets partition the group into equal,
+    // disjoint pieces
+    for (prime, order) in [(17, 4), (17, 1), (17, 16), (97, 8), (97, 3), (65537, 256)] {
+        let cosets = all_cosets(prime, order);
+        assert_eq!(cosets.len() as u64, (prime - 1) / order as u64);
+        assert!(cosets.iter().all(|c| c.len() == order));
+
+        let mut seen = HashSet::new();
+        for element in cosets.iter().flatten() {
+            // Disjoint: no element shows up in two cosets
+            assert!(seen.insert(*element));
+        }
+        // Together they cover every non-zero element
+        assert_eq!(seen, (1..prime).collect::<HashSet<u64>>());
+        println!(
+            "GF({})* splits into {} cosets of size {}",
+            prime,
+            cosets.len(),
+            order
+        );
+    }
+    let cosets = all_cosets(17, 4);
+    assert_eq!(cosets[0], subgroup_of_order(4, 17));
+    assert_eq!(cosets[1], coset(2, &subgroup, 17));
+    println!("Cosets of order 4 mod 17: {:?}", cosets);
+
+    // Z(X) = X^4 - 1 is zero on the subgroup but never on another coset
+    let vanishing = |x: u64| (mod_pow(x, 4, 17) + 16) % 17;
+    assert!(cosets[0].iter().all(|&x| vanishing(x) == 0));
+    assert!(cosets[1..].iter().flatten().all(|&x| vanishing(x) != 0));
+}
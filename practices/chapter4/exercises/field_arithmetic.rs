@@ -0,0 +1,305 @@
+// Field Arithmetic: Every value inside a Halo2 circuit is an element of a
+// finite field, the integers modulo a prime p. These exercises build
+// intuition for how that arithmetic behaves, printing each step of the work
+// so you can follow along with pen and paper.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Helper functions: the product of two values below m can need up to 128
+// bits, so it is computed in u128 before reducing
+fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+// Square-and-multiply exponentiation modulo m
+fn mod_pow(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+// Exercise 1 Solution: 7^100 mod 11
+// Fermat's little theorem says a^(p-1) = 1 (mod p) for a prime p that
+// doesn't divide a. Here p - 1 = 10, so 7^100 = (7^10)^10 = 1^10 = 1.
+fn seven_to_the_hundred_mod_eleven() -> u64 {
+    println!("Step 1: 11 is prime and doesn't divide 7, so Fermat applies");
+    println!("Step 2: 7^10 mod 11 = {}", mod_pow(7, 10, 11));
+    println!("Step 3: 100 = 10 * 10, so 7^100 = (7^10)^10 = 1^10 = 1");
+    let result = mod_pow(7, 100, 11);
+    println!("Check:  7^100 mod 11 = {}", result);
+    result
+}
+
+// Exercise 2 Solution: Elements of order 4 in Z*13
+// The order of a is the smallest k > 0 with a^k = 1. By Lagrange's theorem
+// it divides the group size 12, so for p = 13 the answer is always one of
+// {1, 2, 3, 4, 6, 12}. We simply try every k in turn. Any unit reaches 1
+// within p steps; if a shares a factor with p it never does, and has no
+// order at all.
+fn order(a: u64, p: u64) -> Option<u64> {
+    let mut value = a % p;
+    for k in 1..=p {
+        if value == 1 % p {
+            return Some(k);
+        }
+        value = mod_mul(value, a, p);
+    }
+    None
+}
+
+fn elements_of_order(target: u64, p: u64) -> Vec<u64> {
+    let mut found = Vec::new();
+    for a in 1..p {
+        match order(a, p) {
+            Some(k) => {
+                println!("  ord({:2}) = {:2}", a, k);
+                if k == target {
+                    found.push(a);
+                }
+            }
+            None => println!("  ord({:2}) is undefined, {} is not a unit", a, a),
+        }
+    }
+    found
+}
+
+// Exercise 3 Solution: Discrete logarithms in a small group
+// Find x with g^x = h (mod p) by trying every exponent. This brute force is
+// fine for p = 13, and the fact that nothing much better is known for large
+// groups is what discrete-log cryptography relies on.
+fn discrete_log(g: u64, h: u64, p: u64) -> Option<u64> {
+    let mut value = 1;
+    for x in 0..p - 1 {
+        if value == h % p {
+            return Some(x);
+        }
+        value = mod_mul(value, g, p);
+    }
+    None
+}
+
+// Exercise 4 Solution: Distributivity in Z_p
+fn check_distributivity(p: u64) -> bool {
+    for a in 0..p {
+        for b in 0..p {
+            for c in 0..p {
+                let left = a * ((b + c) % p) % p;
+                let right = (a * b % p + a * c % p) % p;
+                if left != right {
+                    println!("  {} * ({} + {}) != {} * {} + {} * {}", a, b, c, a, b, a, c);
+                    return false;
+                }
+            }
+        }
+    }
+    println!(
+        "  a * (b + c) = a * b + a * c for all {} triples mod {}",
+        p * p * p,
+        p
+    );
+    true
+}
+
+// Exercise 5 Solution: Reducing before multiplying
+// Reducing early keeps each factor below p, so the product is below p^2.
+// That fits in a u64 only while p < 2^32; for larger moduli, like the
+// 255-bit fields Halo2 uses, the product still needs a wider type. Here it
+// is widened to u128, which covers every u64 modulus.
+fn reduce_then_multiply(a: u64, b: u64, p: u64) -> bool {
+    let direct = (a as u128 * b as u128 % p as u128) as u64;
+    let reduced = mod_mul(a % p, b % p, p);
+    println!(
+        "  ({} * {}) mod {} = {}, ((a mod p) * (b mod p)) mod p = ({} * {}) mod {} = {}",
+        a,
+        b,
+        p,
+        direct,
+        a % p,
+        b % p,
+        p,
+        reduced
+    );
+    direct == reduced
+}
+
+// Exercise 6 Solution: A field element type
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FieldElement {
+    value: u64,
+    modulus: u64,
+}
+
+impl FieldElement {
+    fn new(value: u64, modulus: u64) -> FieldElement {
+        FieldElement {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    fn zero(modulus: u64) -> FieldElement {
+        FieldElement::new(0, modulus)
+    }
+
+    fn one(modulus: u64) -> FieldElement {
+        FieldElement::new(1, modulus)
+    }
+
+    // Search for b with a * b = 1. Fermat would be faster, but only works
+    // when the modulus is prime, and here we want to see it fail otherwise.
+    fn inverse(&self) -> Option<FieldElement> {
+        (1..self.modulus)
+            .map(|b| FieldElement::new(b, self.modulus))
+            .find(|&b| *self * b == FieldElement::one(self.modulus))
+    }
+}
+
+impl Add for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: FieldElement) -> FieldElement {
+        let sum = (self.value as u128 + other.value as u128) % self.modulus as u128;
+        FieldElement::new(sum as u64, self.modulus)
+    }
+}
+
+impl Sub for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, other: FieldElement) -> FieldElement {
+        self + (-other)
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: FieldElement) -> FieldElement {
+        FieldElement::new(mod_mul(self.value, other.value, self.modulus), self.modulus)
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        FieldElement::new(self.modulus - self.value, self.modulus)
+    }
+}
+
+// Exercise 7 Solution: Checking the field axioms
+fn verify_field_axioms(elements: &[FieldElement]) -> bool {
+    let Some(first) = elements.first() else {
+        return false;
+    };
+    let zero = FieldElement::zero(first.modulus);
+    let one = FieldElement::one(first.modulus);
+
+    for &a in elements {
+        // Identities
+        if a + zero != a || a * one != a {
+            return false;
+        }
+        // Inverses: every element has a negative, every non-zero one a reciprocal
+        if a + (-a) != zero {
+            return false;
+        }
+        if a != zero && a.inverse().is_none() {
+            return false;
+        }
+
+        for &b in elements {
+            // Commutativity
+            if a + b != b + a || a * b != b * a {
+                return false;
+            }
+            for &c in elements {
+                // Associativity
+                if (a + b) + c != a + (b + c) || (a * b) * c != a * (b * c) {
+                    return false;
+                }
+                // Distributivity
+                if a * (b + c) != a * b + a * c {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+fn main() {
+    // Test Exercise 1
+    println!("\n=== Exercise 1: 7^100 mod 11 ===");
+    assert_eq!(seven_to_the_hundred_mod_eleven(), 1);
+
+    // Test Exercise 2
+    println!("\n=== Exercise 2: Elements of order 4 in Z*13 ===");
+    let order_four = elements_of_order(4, 13);
+    println!("Elements of order 4: {:?}", order_four);
+    assert_eq!(order_four, vec![5, 8]);
+    // In Z_12 only 1, 5, 7 and 11 are units, and each squares to 1
+    println!("Elements of order 2 in Z_12:");
+    assert_eq!(elements_of_order(2, 12), vec![5, 7, 11]);
+    assert_eq!(order(4, 12), None);
+
+    // Test Exercise 3: 2 generates Z*13, so every element has a log
+    println!("\n=== Exercise 3: Discrete logs base 2 mod 13 ===");
+    for h in 1..13 {
+        let x = discrete_log(2, h, 13).unwrap();
+        println!(
+            "  log_2({:2}) = {:2}  since 2^{} mod 13 = {}",
+            h,
+            x,
+            x,
+            mod_pow(2, x, 13)
+        );
+        assert_eq!(mod_pow(2, x, 13), h);
+    }
+    // 3 has order 3, so it only reaches {1, 3, 9}
+    assert_eq!(discrete_log(3, 2, 13), None);
+
+    // Test Exercise 4
+    println!("\n=== Exercise 4: Distributivity ===");
+    assert!(check_distributivity(7));
+
+    // Test Exercise 5
+    println!("\n=== Exercise 5: Reduce, then multiply ===");
+    assert!(reduce_then_multiply(1_000_003, 999_999_937, 17));
+    assert!(reduce_then_multiply(u32::MAX as u64, 123_456_789, 65537));
+    // A modulus above 2^32: the reduced factors alone overflow a u64 product
+    let big_prime = 18_446_744_073_709_551_557; // largest prime below 2^64
+    assert!(reduce_then_multiply(
+        big_prime - 1,
+        big_prime - 2,
+        big_prime
+    ));
+
+    // Test Exercise 6
+    let a = FieldElement::new(5, 17);
+    let b = FieldElement::new(15, 17);
+    assert_eq!(a + b, FieldElement::new(3, 17));
+    assert_eq!(a - b, FieldElement::new(7, 17));
+    assert_eq!(a * b, FieldElement::new(7, 17));
+    assert_eq!(a * a.inverse().unwrap(), FieldElement::one(17));
+    let x = FieldElement::new(big_prime - 1, big_prime);
+    assert_eq!(x * x, FieldElement::one(big_prime));
+    assert_eq!(x + x, FieldElement::new(big_prime - 2, big_prime));
+
+    // Test Exercise 7: GF(17) is a field, but Z_12 is not (2 has no inverse)
+    println!("\n=== Exercise 7: Field axioms ===");
+    let gf17: Vec<FieldElement> = (0..17).map(|v| FieldElement::new(v, 17)).collect();
+    assert!(verify_field_axioms(&gf17));
+    println!("  GF(17) satisfies all field axioms");
+
+    let z12: Vec<FieldElement> = (0..12).map(|v| FieldElement::new(v, 12)).collect();
+    assert!(!verify_field_axioms(&z12));
+    println!("  Z_12 is not a field");
+}
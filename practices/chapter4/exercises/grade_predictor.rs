@@ -0,0 +1,127 @@
+// Grade Predictor: Fit a straight line through a student's past grades and
+// use it to guess the next one. Ordinary least squares has a closed-form
+// answer, so no iteration is needed. Simple models like this are also the
+// starting point for ZK-ML, where a prover shows that a prediction came
+// from a committed model without revealing the model's weights.
+
+// Exercise 1 Solution: The model
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LinearModel {
+    slope: f64,
+    intercept: f64,
+}
+
+impl LinearModel {
+    fn predict(&self, x: f64) -> f64 {
+        self.slope * x + self.intercept
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+// Exercise 2 Solution: Ordinary least squares
+//     slope = sum((x - mean_x) * (y - mean_y)) / sum((x - mean_x)^2)
+//     intercept = mean_y - slope * mean_x
+// Returns None when there is no unique line: fewer than two points,
+// mismatched lengths, or every x the same.
+fn fit_linear(x: &[f64], y: &[f64]) -> Option<LinearModel> {
+    if x.len() != y.len() || x.len() < 2 {
+        return None;
+    }
+    let mean_x = mean(x);
+    let mean_y = mean(y);
+    let covariance: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(xi, yi)| (xi - mean_x) * (yi - mean_y))
+        .sum();
+    let variance: f64 = x.iter().map(|xi| (xi - mean_x).powi(2)).sum();
+    if variance == 0.0 {
+        return None;
+    }
+
+    let slope = covariance / variance;
+    Some(LinearModel {
+        slope,
+        intercept: mean_y - slope * mean_x,
+    })
+}
+
+// Exercise 3 Solution: Goodness of fit
+// R^2 = 1 - (residual sum of squares) / (total sum of squares). A flat
+// line of y values has nothing to explain, so it counts as a perfect fit
+// when the model hits every point.
+fn r_squared(model: &LinearModel, x: &[f64], y: &[f64]) -> f64 {
+    let mean_y = mean(y);
+    let residual: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(&xi, &yi)| (yi - model.predict(xi)).powi(2))
+        .sum();
+    let total: f64 = y.iter().map(|yi| (yi - mean_y).powi(2)).sum();
+    if total == 0.0 {
+        return if residual == 0.0 { 1.0 } else { 0.0 };
+    }
+    1.0 - residual / total
+}
+
+// Exercise 4 Solution: Predicting a student's next grade
+// Helper type: just enough of a student for this exercise
+struct Student {
+    name: String,
+    grades: Vec<f64>,
+}
+
+// The assignment index is x and the grade is y, so the next assignment is
+// x = grades.len().
+fn predict_next_grade(student: &Student) -> Option<f64> {
+    let x: Vec<f64> = (0..student.grades.len()).map(|i| i as f64).collect();
+    let model = fit_linear(&x, &student.grades)?;
+    Some(model.predict(student.grades.len() as f64))
+}
+
+fn main() {
+    // Test Exercise 2: exact recovery of a line
+    let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let y: Vec<f64> = x.iter().map(|xi| 3.0 * xi + 60.0).collect();
+    let model = fit_linear(&x, &y).unwrap();
+    println!("Fitted: y = {} * x + {}", model.slope, model.intercept);
+    assert!((model.slope - 3.0).abs() < 1e-9);
+    assert!((model.intercept - 60.0).abs() < 1e-9);
+
+    // Not enough information for a line
+    assert_eq!(fit_linear(&[1.0], &[5.0]), None);
+    assert_eq!(fit_linear(&[2.0, 2.0], &[5.0, 7.0]), None);
+    assert_eq!(fit_linear(&[1.0, 2.0], &[5.0]), None);
+
+    // Test Exercise 3: perfectly linear data has R^2 = 1
+    assert!((r_squared(&model, &x, &y) - 1.0).abs() < 1e-12);
+
+    // Noisy data fits less well, but still better than the mean
+    let noisy = [61.0, 62.0, 67.0, 68.0, 73.0];
+    let noisy_model = fit_linear(&x, &noisy).unwrap();
+    let r2 = r_squared(&noisy_model, &x, &noisy);
+    println!("R^2 on noisy data: {:.4}", r2);
+    assert!(r2 > 0.9 && r2 < 1.0);
+
+    // Test Exercise 4: the trend is extrapolated
+    let alice = Student {
+        name: "Alice".to_string(),
+        grades: vec![70.0, 74.0, 78.0, 82.0],
+    };
+    let next = predict_next_grade(&alice).unwrap();
+    println!("{}'s predicted next grade: {:.1}", alice.name, next);
+    assert!((next - 86.0).abs() < 1e-9);
+
+    let newcomer = Student {
+        name: "Bob".to_string(),
+        grades: vec![90.0],
+    };
+    assert_eq!(predict_next_grade(&newcomer), None);
+    println!(
+        "{} needs at least two grades for a prediction",
+        newcomer.name
+    );
+}
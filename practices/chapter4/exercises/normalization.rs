@@ -0,0 +1,183 @@
+// Grade Normalization: Put grades from different sections on a common
+// scale, so an 80 from a hard section and an 80 from an easy one can be
+// compared fairly. Each strategy is one variant of an enum, and a single
+// match decides how a grade is transformed.
+
+// Exercise 1 Solution: The strategies
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NormalizationStrategy {
+    // How many standard deviations from the mean
+    ZScore,
+    // Scaled so the lowest grade is 0 and the highest is 1
+    MinMax,
+    // The percentage of the class that scored lower (ties count half)
+    PercentileRank,
+    // A flat number of points added to everyone
+    Curve { adjustment: f64 },
+}
+
+// Helper functions: population mean and standard deviation
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn std_dev(values: &[f64]) -> f64 {
+    let m = mean(values);
+    (values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / values.len() as f64).sqrt()
+}
+
+// Exercise 2 Solution: Normalizing one value against a class
+// When every grade in the class is the same there is no spread to divide
+// by, so ZScore and MinMax put everyone at 0.
+fn normalize_value(value: f64, class_grades: &[f64], strategy: NormalizationStrategy) -> f64 {
+    match strategy {
+        NormalizationStrategy::ZScore => {
+            let sd = std_dev(class_grades);
+            if sd == 0.0 {
+                0.0
+            } else {
+                (value - mean(class_grades)) / sd
+            }
+        }
+        NormalizationStrategy::MinMax => {
+            let min = class_grades.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = class_grades
+                .iter()
+                .copied()
+                .fold(f64::NEG_INFINITY, f64::max);
+            if max == min {
+                0.0
+            } else {
+                (value - min) / (max - min)
+            }
+        }
+        NormalizationStrategy::PercentileRank => {
+            let below = class_grades.iter().filter(|&&g| g < value).count() as f64;
+            let equal = class_grades.iter().filter(|&&g| g == value).count() as f64;
+            100.0 * (below + 0.5 * equal) / class_grades.len() as f64
+        }
+        NormalizationStrategy::Curve { adjustment } => value + adjustment,
+    }
+}
+
+fn normalize_grades(grades: &[f64], strategy: NormalizationStrategy) -> Vec<f64> {
+    grades
+        .iter()
+        .map(|&g| normalize_value(g, grades, strategy))
+        .collect()
+}
+
+// Helper type: just enough of a student for this exercise
+struct Student {
+    name: String,
+    grades: Vec<f64>,
+}
+
+impl Student {
+    fn average(&self) -> f64 {
+        assert!(!self.grades.is_empty(), "{} has no grades", self.name);
+        mean(&self.grades)
+    }
+}
+
+// Exercise 3 Solution: One student against the class
+fn normalize_student(
+    student: &Student,
+    class_grades: &[f64],
+    strategy: NormalizationStrategy,
+) -> f64 {
+    normalize_value(student.average(), class_grades, strategy)
+}
+
+// Exercise 4 Solution: Equating two sections
+// Turn each student's average into a z-score within their own section,
+// then map every z-score back onto the combined class's mean and spread.
+// Afterwards the top student in each section lands in the same place
+// relative to the whole class, whatever their section's difficulty.
+fn equate_sections(section_a: &[Student], section_b: &[Student]) -> (Vec<f64>, Vec<f64>) {
+    let averages_a: Vec<f64> = section_a.iter().map(Student::average).collect();
+    let averages_b: Vec<f64> = section_b.iter().map(Student::average).collect();
+    let combined = [averages_a.as_slice(), averages_b.as_slice()].concat();
+    let (combined_mean, combined_sd) = (mean(&combined), std_dev(&combined));
+
+    let equate = |averages: &[f64]| -> Vec<f64> {
+        normalize_grades(averages, NormalizationStrategy::ZScore)
+            .iter()
+            .map(|z| combined_mean + z * combined_sd)
+            .collect()
+    };
+    (equate(&averages_a), equate(&averages_b))
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn main() {
+    let grades = [62.0, 71.0, 75.0, 80.0, 84.0, 88.0, 95.0];
+
+    // Test Exercise 2: z-scores have mean 0 and standard deviation 1
+    let z = normalize_grades(&grades, NormalizationStrategy::ZScore);
+    println!("Z-scores: {:.3?}", z);
+    assert!(close(mean(&z), 0.0));
+    assert!(close(std_dev(&z), 1.0));
+
+    // Min-max maps onto [0, 1], hitting both ends
+    let scaled = normalize_grades(&grades, NormalizationStrategy::MinMax);
+    assert!(scaled.iter().all(|s| (0.0..=1.0).contains(s)));
+    assert_eq!(scaled[0], 0.0);
+    assert_eq!(scaled[grades.len() - 1], 1.0);
+
+    // Percentile ranks keep the order and stay within 0..100
+    let ranks = normalize_grades(&grades, NormalizationStrategy::PercentileRank);
+    assert!(ranks.windows(2).all(|w| w[0] < w[1]));
+    assert!(ranks.iter().all(|r| (0.0..=100.0).contains(r)));
+    assert!(close(ranks[3], 50.0)); // the middle of seven grades
+
+    // The curve adds exactly the adjustment
+    let curve = NormalizationStrategy::Curve { adjustment: 5.0 };
+    let curved = normalize_grades(&grades, curve);
+    for (before, after) in grades.iter().zip(&curved) {
+        assert_eq!(after - before, 5.0);
+    }
+
+    // A class where everyone has the same grade
+    assert_eq!(
+        normalize_grades(&[70.0, 70.0], NormalizationStrategy::ZScore),
+        vec![0.0, 0.0]
+    );
+    assert!(normalize_grades(&[], NormalizationStrategy::MinMax).is_empty());
+
+    // Test Exercise 3
+    let alice = Student {
+        name: "Alice".to_string(),
+        grades: vec![90.0, 100.0],
+    };
+    let z = normalize_student(&alice, &grades, NormalizationStrategy::ZScore);
+    println!("{}'s z-score against the class: {:.3}", alice.name, z);
+    assert!(z > 1.0);
+    assert_eq!(normalize_student(&alice, &grades, curve), 100.0);
+
+    // Test Exercise 4: a hard section and an easy section
+    let student = |name: &str, grade: f64| Student {
+        name: name.to_string(),
+        grades: vec![grade],
+    };
+    let hard = [
+        student("A1", 55.0),
+        student("A2", 65.0),
+        student("A3", 75.0),
+    ];
+    let easy = [
+        student("B1", 80.0),
+        student("B2", 85.0),
+        student("B3", 90.0),
+    ];
+    let (equated_hard, equated_easy) = equate_sections(&hard, &easy);
+    println!("Hard section equated: {:.2?}", equated_hard);
+    println!("Easy section equated: {:.2?}", equated_easy);
+    // The best and worst student of each section end up level
+    assert!(close(equated_hard[2], equated_easy[2]));
+    assert!(close(equated_hard[0], equated_easy[0]));
+    assert!(close(mean(&equated_hard), mean(&equated_easy)));
+}
@@ -0,0 +1,197 @@
+// Proof Size Estimator: Predict how big a Halo2 proof will be, and how long
+// it takes to verify, from the shape of the circuit. The formulas count
+// what a proof actually contains (a commitment per column, evaluations
+// at the challenge point, and the opening argument) but the constants are
+// rough, so treat the numbers as estimates for comparing designs, not as
+// exact byte counts.
+
+use std::time::Duration;
+
+// Both commitment schemes use 32-byte curve points and 32-byte scalars
+// (Pasta curves for IPA, compressed BN254 points for KZG)
+const POINT_BYTES: usize = 32;
+const SCALAR_BYTES: usize = 32;
+
+// Exercise 1 Solution: Describing a circuit
+#[derive(Debug, Clone, Copy)]
+struct CircuitParams {
+    n_rows: usize,
+    n_advice_columns: usize,
+    n_fixed_columns: usize,
+    n_lookup_tables: usize,
+    n_copy_constraints: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ProofScheme {
+    Ipa,
+    Kzg,
+}
+
+impl CircuitParams {
+    // Halo2 pads the circuit to 2^k rows
+    fn k(&self) -> u32 {
+        self.n_rows.max(2).next_power_of_two().trailing_zeros()
+    }
+
+    // Everything both schemes send before the opening argument
+    fn common_proof_bytes(&self) -> usize {
+        let advice = self.n_advice_columns * (POINT_BYTES + SCALAR_BYTES);
+        let fixed = self.n_fixed_columns * SCALAR_BYTES;
+        // Each lookup commits to a permuted input, a permuted table and a
+        // grand product, and opens them at a handful of points
+        let lookups = self.n_lookup_tables * (3 * POINT_BYTES + 5 * SCALAR_BYTES);
+        // Copy constraints need the permutation argument: one grand product
+        // per chunk of 3 columns, each opened at three points
+        let permutation = if self.n_copy_constraints > 0 {
+            let chunks = self.n_advice_columns.div_ceil(3).max(1);
+            chunks * (POINT_BYTES + 3 * SCALAR_BYTES)
+        } else {
+            0
+        };
+        // The quotient polynomial is split into a few pieces
+        let vanishing = 4 * POINT_BYTES + SCALAR_BYTES;
+        advice + fixed + lookups + permutation + vanishing
+    }
+}
+
+// Exercise 2 Solution: Proof sizes
+// The IPA opening halves the vector each round and sends two points per
+// round, so it grows with k. The KZG opening is a constant couple of points.
+fn estimate_ipa_proof_size(params: &CircuitParams) -> usize {
+    let opening = 2 * params.k() as usize * POINT_BYTES + 2 * SCALAR_BYTES;
+    params.common_proof_bytes() + opening
+}
+
+fn estimate_kzg_proof_size(params: &CircuitParams) -> usize {
+    let opening = 2 * POINT_BYTES;
+    params.common_proof_bytes() + opening
+}
+
+// Exercise 3 Solution: Verification time
+// An IPA verifier has to do a multi-scalar multiplication as large as the
+// circuit, so it is linear in the number of rows. A KZG verifier does two
+// pairings and a little work per column, whatever the circuit size.
+fn estimate_verification_time(params: &CircuitParams, scheme: &ProofScheme) -> Duration {
+    let columns = params.n_advice_columns + params.n_fixed_columns + params.n_lookup_tables;
+    let per_column = Duration::from_micros(20) * columns as u32;
+    match scheme {
+        // About a microsecond per padded row for the MSM
+        ProofScheme::Ipa => {
+            let padded_rows = 1u64 << params.k();
+            Duration::from_millis(2) + Duration::from_nanos(1_000 * padded_rows) + per_column
+        }
+        ProofScheme::Kzg => Duration::from_millis(3) + per_column,
+    }
+}
+
+// Exercise 4 Solution: A comparison table
+fn comparison_table(base: &CircuitParams, k_values: &[u32]) -> String {
+    let mut table = format!(
+        "{:>3} | {:>9} | {:>9} | {:>9} | {:>12} | {:>12}\n",
+        "k", "rows", "IPA bytes", "KZG bytes", "IPA verify", "KZG verify"
+    );
+    for &k in k_values {
+        let params = CircuitParams {
+            n_rows: 1 << k,
+            ..*base
+        };
+        table += &format!(
+            "{:>3} | {:>9} | {:>9} | {:>9} | {:>12} | {:>12}\n",
+            k,
+            params.n_rows,
+            estimate_ipa_proof_size(&params),
+            estimate_kzg_proof_size(&params),
+            format!(
+                "{:?}",
+                estimate_verification_time(&params, &ProofScheme::Ipa)
+            ),
+            format!(
+                "{:?}",
+                estimate_verification_time(&params, &ProofScheme::Kzg)
+            ),
+        );
+    }
+    table
+}
+
+fn main() {
+    let small = CircuitParams {
+        n_rows: 1 << 10,
+        n_advice_columns: 3,
+        n_fixed_columns: 2,
+        n_lookup_tables: 1,
+        n_copy_constraints: 50,
+    };
+
+    // Test Exercise 1: rows are rounded up to a power of two
+    assert_eq!(small.k(), 10);
+    assert_eq!(
+        CircuitParams {
+            n_rows: 1000,
+            ..small
+        }
+        .k(),
+        10
+    );
+    assert_eq!(
+        CircuitParams {
+            n_rows: 1025,
+            ..small
+        }
+        .k(),
+        11
+    );
+
+    // Test Exercise 2: more rows or more columns means a bigger proof
+    let more_rows = CircuitParams {
+        n_rows: 1 << 16,
+        ..small
+    };
+    let more_columns = CircuitParams {
+        n_advice_columns: 10,
+        ..small
+    };
+    let with_lookups = CircuitParams {
+        n_lookup_tables: 4,
+        ..small
+    };
+    for bigger in [&more_rows, &more_columns, &with_lookups] {
+        assert!(estimate_ipa_proof_size(bigger) > estimate_ipa_proof_size(&small));
+        assert!(estimate_kzg_proof_size(bigger) >= estimate_kzg_proof_size(&small));
+    }
+    assert!(estimate_kzg_proof_size(&more_columns) > estimate_kzg_proof_size(&small));
+
+    // IPA pays for its transparent setup with a bigger proof; KZG doesn't
+    // depend on the number of rows at all
+    assert!(estimate_ipa_proof_size(&small) > estimate_kzg_proof_size(&small));
+    assert_eq!(
+        estimate_kzg_proof_size(&more_rows),
+        estimate_kzg_proof_size(&small)
+    );
+
+    // Test Exercise 3
+    let ipa = ProofScheme::Ipa;
+    let kzg = ProofScheme::Kzg;
+    assert!(
+        estimate_verification_time(&more_rows, &ipa) > estimate_verification_time(&small, &ipa)
+    );
+    assert_eq!(
+        estimate_verification_time(&more_rows, &kzg),
+        estimate_verification_time(&small, &kzg)
+    );
+    assert!(
+        estimate_verification_time(&more_columns, &kzg) > estimate_verification_time(&small, &kzg)
+    );
+
+    // Test Exercise 4: a header plus one line per k
+    let table = comparison_table(&small, &[8, 12, 16, 20]);
+    println!("{}", table);
+    assert_eq!(table.lines().count(), 5);
+    assert!(table
+        .lines()
+        .nth(1)
+        .unwrap()
+        .trim_start()
+        .starts_with("8 |"));
+}
@@ -0,0 +1,209 @@
+// Square Roots in a Finite Field: Given a, find x with x * x = a (mod p).
+// Only half of the non-zero elements have a square root, and those that
+// do have exactly two, x and -x. Elliptic-curve points are often stored
+// as just the x coordinate plus one bit, and decompressing them means
+// solving y^2 = x^3 + ax + b for y, so this is the square root that does it.
+
+use std::ops::Mul;
+
+// Helper functions: modular multiplication in u128 and square-and-multiply
+fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+fn mod_pow(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+// Helper type: just enough of a field element for this exercise
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FieldElement {
+    value: u64,
+    modulus: u64,
+}
+
+impl FieldElement {
+    fn new(value: u64, modulus: u64) -> FieldElement {
+        FieldElement {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    fn pow(self, exp: u64) -> FieldElement {
+        FieldElement::new(mod_pow(self.value, exp, self.modulus), self.modulus)
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: FieldElement) -> FieldElement {
+        FieldElement::new(mod_mul(self.value, other.value, self.modulus), self.modulus)
+    }
+}
+
+// Exercise 1 Solution: The Legendre symbol
+// 0 if p divides a, 1 if a is a non-zero square mod p, -1 otherwise. By
+// Euler's criterion a^((p-1)/2) is always 1 or -1 for non-zero a, and
+// it's 1 exactly for the squares. `p` must be an odd prime.
+fn legendre_symbol(a: u64, p: u64) -> i8 {
+    match mod_pow(a, (p - 1) / 2, p) {
+        0 => 0,
+        1 => 1,
+        _ => -1,
+    }
+}
+
+// Exercise 2 Solution: Square roots with Tonelli-Shanks
+struct FiniteField {
+    prime: u64,
+}
+
+impl FiniteField {
+    fn element(&self, value: u64) -> FieldElement {
+        FieldElement::new(value, self.prime)
+    }
+
+    // Euler's criterion. Zero is a square (0 * 0 = 0) but doesn't pass
+    // this test, so sqrt handles it on its own.
+    fn is_quadratic_residue(&self, a: FieldElement) -> bool {
+        a.pow((self.prime - 1) / 2).value == 1
+    }
+
+    // Write p - 1 = q * 2^s with q odd. If s = 1 (p = 3 mod 4) then
+    // a^((q+1)/2) is already the root. Otherwise r = a^((q+1)/2) starts out
+    // as a guess with r^2 = a * t, and each round uses a power of a
+    // non-residue z to cut the order of the error t until t = 1.
+    fn sqrt(&self, a: FieldElement) -> Option<FieldElement> {
+        let p = self.prime;
+        if a.value == 0 || p == 2 {
+            return Some(a);
+        }
+        if !self.is_quadratic_residue(a) {
+            return None;
+        }
+
+        let s = (p - 1).trailing_zeros();
+        let q = (p - 1) >> s;
+        // Half of all elements are non-residues, so this search is short
+        let z = (2..p)
+            .map(|v| self.element(v))
+            .find(|&v| !self.is_quadratic_residue(v))
+            .unwrap();
+
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = a.pow(q);
+        let mut r = a.pow(q.div_ceil(2));
+        while t.value != 1 {
+            // The smallest i with t^(2^i) = 1; it's below m because t's
+            // order keeps shrinking
+            let mut i = 0;
+            let mut t_power = t;
+            while t_power.value != 1 {
+                t_power = t_power * t_power;
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t = t * c;
+            r = r * b;
+        }
+        Some(r)
+    }
+
+    // Both roots, smaller value first; just [0] for zero; empty when there
+    // are none
+    fn all_square_roots(&self, a: FieldElement) -> Vec<FieldElement> {
+        let Some(root) = self.sqrt(a) else {
+            return Vec::new();
+        };
+        let other = self.element(self.prime - root.value);
+        if other == root {
+            return vec![root];
+        }
+        let mut roots = vec![root, other];
+        roots.sort_by_key(|r| r.value);
+        roots
+    }
+}
+
+fn main() {
+    // Test Exercise 1: the symbol for small primes
+    assert_eq!(legendre_symbol(0, 7), 0);
+    assert_eq!(legendre_symbol(14, 7), 0);
+    assert_eq!(legendre_symbol(2, 7), 1); // 3 * 3 = 9 = 2
+    assert_eq!(legendre_symbol(3, 7), -1);
+    // It's multiplicative: (ab/p) = (a/p)(b/p)
+    for a in 1..23 {
+        for b in 1..23 {
+            assert_eq!(
+                legendre_symbol(a * b, 23),
+                legendre_symbol(a, 23) * legendre_symbol(b, 23)
+            );
+        }
+    }
+
+    // Test Exercise 2: every residue has a root, no non-residue does.
+    // 7 and 23 are 3 mod 4; 13 and 41 have s = 2 and 3; 97 has s = 5
+    for p in [3, 7, 13, 17, 23, 41, 97] {
+        let field = FiniteField { prime: p };
+        let mut residues = 0;
+        for v in 1..p {
+            let a = field.element(v);
+            match field.sqrt(a) {
+                Some(root) => {
+                    assert!(field.is_quadratic_residue(a));
+                    assert_eq!(root * root, a);
+                    assert_eq!(field.all_square_roots(a).len(), 2);
+                    residues += 1;
+                }
+                None => {
+                    assert!(!field.is_quadratic_residue(a));
+                    assert!(field.all_square_roots(a).is_empty());
+                }
+            }
+        }
+        assert_eq!(residues, (p - 1) / 2);
+        println!("GF({}) has {} quadratic residues", p, residues);
+    }
+
+    let field = FiniteField { prime: 13 };
+    assert_eq!(
+        field.all_square_roots(field.element(10)),
+        vec![field.element(6), field.element(7)]
+    );
+    assert_eq!(
+        field.all_square_roots(field.element(0)),
+        vec![field.element(0)]
+    );
+    assert!(!field.is_quadratic_residue(field.element(0)));
+
+    // A large prime with p - 1 = 119 * 2^23, where Tonelli-Shanks has to
+    // do real work
+    let field = FiniteField { prime: 998_244_353 };
+    for v in (1..1_000_000u64).step_by(9_973) {
+        let square = field.element(v) * field.element(v);
+        let root = field.sqrt(square).unwrap();
+        assert_eq!(root * root, square);
+        assert!(root.value == v || root.value == field.prime - v);
+    }
+    let square = field.element(123_456) * field.element(123_456);
+    let root = field.sqrt(square).unwrap();
+    println!(
+        "sqrt({}) mod {} = {}",
+        square.value, field.prime, root.value
+    );
+    assert_eq!(field.sqrt(field.element(3)), None); // 3 generates the group
+}
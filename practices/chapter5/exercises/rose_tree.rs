@@ -0,0 +1,205 @@
+// Rose Tree: A tree where every node can have any number of children.
+// It's a natural fit for nested grade categories, e.g. a quarter contains
+// categories, and each category contains assignments. The generic methods
+// below work for any value type T.
+
+use std::fmt::{self, Display};
+
+// Exercise 1 Solution: The tree type
+#[derive(Debug)]
+struct RoseTree<T> {
+    value: T,
+    children: Vec<RoseTree<T>>,
+}
+
+impl<T> RoseTree<T> {
+    fn leaf(value: T) -> Self {
+        RoseTree {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    fn node(value: T, children: Vec<RoseTree<T>>) -> Self {
+        RoseTree { value, children }
+    }
+
+    // Exercise 2 Solution: Transforming every value
+    // Returns a new tree with the same shape.
+    fn map<U>(&self, f: impl Fn(&T) -> U) -> RoseTree<U> {
+        self.map_ref(&f)
+    }
+
+    // The recursion takes the closure by reference so it isn't moved away
+    // on the first recursive call.
+    fn map_ref<U>(&self, f: &impl Fn(&T) -> U) -> RoseTree<U> {
+        RoseTree {
+            value: f(&self.value),
+            children: self.children.iter().map(|child| child.map_ref(f)).collect(),
+        }
+    }
+
+    // Exercise 3 Solution: Folding in pre-order (a node before its children)
+    fn fold<B>(&self, init: B, f: impl Fn(B, &T) -> B) -> B {
+        self.fold_ref(init, &f)
+    }
+
+    fn fold_ref<B>(&self, init: B, f: &impl Fn(B, &T) -> B) -> B {
+        let acc = f(init, &self.value);
+        self.children
+            .iter()
+            .fold(acc, |acc, child| child.fold_ref(acc, f))
+    }
+
+    // Exercise 4 Solution: Queries
+    // A single node has depth 1.
+    fn depth(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(|child| child.depth())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn leaves(&self) -> Vec<&T> {
+        if self.children.is_empty() {
+            return vec![&self.value];
+        }
+        self.children
+            .iter()
+            .flat_map(|child| child.leaves())
+            .collect()
+    }
+
+    fn flatten(&self) -> Vec<&T> {
+        let mut values = vec![&self.value];
+        for child in &self.children {
+            values.extend(child.flatten());
+        }
+        values
+    }
+
+    fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<&T> {
+        self.find_ref(&predicate)
+    }
+
+    fn find_ref(&self, predicate: &impl Fn(&T) -> bool) -> Option<&T> {
+        if predicate(&self.value) {
+            return Some(&self.value);
+        }
+        self.children
+            .iter()
+            .find_map(|child| child.find_ref(predicate))
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter, level: usize) -> fmt::Result
+    where
+        T: Display,
+    {
+        writeln!(f, "{}{}", "  ".repeat(level), self.value)?;
+        for child in &self.children {
+            child.fmt_indented(f, level + 1)?;
+        }
+        Ok(())
+    }
+}
+
+// Exercise 5 Solution: Indented printing
+impl<T: Display> Display for RoseTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct GradeCategory {
+    name: String,
+    points: f64,
+}
+
+impl GradeCategory {
+    fn new(name: &str, points: f64) -> Self {
+        GradeCategory {
+            name: name.to_string(),
+            points,
+        }
+    }
+}
+
+impl Display for GradeCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.points)
+    }
+}
+
+fn main() {
+    // Quarter -> categories -> assignments. Only assignments carry points.
+    let quarter = RoseTree::node(
+        GradeCategory::new("Quarter 1", 0.0),
+        vec![
+            RoseTree::node(
+                GradeCategory::new("Homework", 0.0),
+                vec![
+                    RoseTree::leaf(GradeCategory::new("HW1", 9.0)),
+                    RoseTree::leaf(GradeCategory::new("HW2", 10.0)),
+                ],
+            ),
+            RoseTree::node(
+                GradeCategory::new("Exams", 0.0),
+                vec![
+                    RoseTree::leaf(GradeCategory::new("Midterm", 45.0)),
+                    RoseTree::leaf(GradeCategory::new("Final", 88.0)),
+                ],
+            ),
+            RoseTree::leaf(GradeCategory::new("Participation", 5.0)),
+        ],
+    );
+
+    println!("{}", quarter);
+
+    // Test depth, leaves and flatten
+    assert_eq!(quarter.depth(), 3);
+    let leaf_names: Vec<&str> = quarter.leaves().iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        leaf_names,
+        vec!["HW1", "HW2", "Midterm", "Final", "Participation"]
+    );
+    let all_names: Vec<&str> = quarter.flatten().iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        all_names,
+        vec![
+            "Quarter 1",
+            "Homework",
+            "HW1",
+            "HW2",
+            "Exams",
+            "Midterm",
+            "Final",
+            "Participation"
+        ]
+    );
+
+    // Test fold: total points, and pre-order visiting
+    assert_eq!(quarter.fold(0.0, |total, c| total + c.points), 157.0);
+    let visited = quarter.fold(String::new(), |acc, c| acc + &c.name[..1]);
+    assert_eq!(visited, "QHHHEMFP");
+
+    // Test map: same shape, new values
+    let names = quarter.map(|c| c.name.to_uppercase());
+    assert_eq!(names.depth(), quarter.depth());
+    assert_eq!(names.flatten().len(), 8);
+    assert_eq!(names.value, "QUARTER 1");
+
+    // Test find
+    assert_eq!(
+        quarter.find(|c| c.points > 50.0),
+        Some(&GradeCategory::new("Final", 88.0))
+    );
+    assert_eq!(quarter.find(|c| c.name == "Lab"), None);
+
+    // Test Display: one line per node, indented by level
+    let printed = quarter.to_string();
+    assert!(printed.starts_with("Quarter 1 (0)\n  Homework (0)\n    HW1 (9)\n"));
+    assert_eq!(printed.lines().count(), 8);
+}
@@ -0,0 +1,202 @@
+// Running Median: Keep the median of a growing list of grades up to date
+// without re-sorting. The smaller half of the values lives in a max-heap
+// and the larger half in a min-heap, so the middle of the data is always
+// at the top of one or both heaps.
+//
+// BinaryHeap needs its items to implement Ord, which f64 doesn't (NaN is
+// not equal to itself), so the first step is a wrapper that does.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+// Exercise 1 Solution: A totally ordered f64
+// Numbers compare as usual and every NaN sorts after every number.
+#[derive(Debug, Clone, Copy)]
+struct OrderedF64(f64);
+
+impl Ord for OrderedF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.0.partial_cmp(&other.0).unwrap(),
+        }
+    }
+}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equality has to agree with cmp, so two NaNs are equal here
+impl PartialEq for OrderedF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedF64 {}
+
+// Exercise 2 Solution: Two heaps
+// Invariants: every value in `lower` is <= every value in `upper`, and
+// `lower` holds the same number of values as `upper` or one more.
+struct RunningMedian {
+    lower: BinaryHeap<OrderedF64>,
+    upper: BinaryHeap<Reverse<OrderedF64>>,
+}
+
+impl RunningMedian {
+    fn new() -> Self {
+        RunningMedian {
+            lower: BinaryHeap::new(),
+            upper: BinaryHeap::new(),
+        }
+    }
+
+    fn insert(&mut self, value: f64) {
+        let value = OrderedF64(value);
+        match self.lower.peek() {
+            Some(&top) if value > top => self.upper.push(Reverse(value)),
+            _ => self.lower.push(value),
+        }
+
+        // Move one value across if either side got too big
+        if self.lower.len() > self.upper.len() + 1 {
+            let moved = self.lower.pop().unwrap();
+            self.upper.push(Reverse(moved));
+        } else if self.upper.len() > self.lower.len() {
+            let Reverse(moved) = self.upper.pop().unwrap();
+            self.lower.push(moved);
+        }
+    }
+
+    fn median(&self) -> Option<f64> {
+        let low = self.lower.peek()?.0;
+        if self.lower.len() > self.upper.len() {
+            return Some(low);
+        }
+        let Reverse(high) = self.upper.peek()?;
+        Some((low + high.0) / 2.0)
+    }
+
+    fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+}
+
+// Exercise 3 Solution: The k-th smallest value in linear time
+// Median of medians: split into groups of 5, take each group's median,
+// and recursively find the median of those to use as the pivot. That
+// pivot is guaranteed to have at least 30% of the data on each side, so
+// every step throws a constant fraction away. `k` counts from 0.
+fn median_of_medians(data: &[f64], k: usize) -> f64 {
+    assert!(
+        k < data.len(),
+        "k = {} out of range for {} values",
+        k,
+        data.len()
+    );
+    if data.len() <= 5 {
+        let mut small: Vec<OrderedF64> = data.iter().map(|&v| OrderedF64(v)).collect();
+        small.sort();
+        return small[k].0;
+    }
+
+    let medians: Vec<f64> = data
+        .chunks(5)
+        .map(|group| median_of_medians(group, group.len() / 2))
+        .collect();
+    let pivot = OrderedF64(median_of_medians(&medians, medians.len() / 2));
+
+    let below: Vec<f64> = data
+        .iter()
+        .copied()
+        .filter(|&v| OrderedF64(v) < pivot)
+        .collect();
+    let equal = data.iter().filter(|&&v| OrderedF64(v) == pivot).count();
+    if k < below.len() {
+        median_of_medians(&below, k)
+    } else if k < below.len() + equal {
+        pivot.0
+    } else {
+        let above: Vec<f64> = data
+            .iter()
+            .copied()
+            .filter(|&v| OrderedF64(v) > pivot)
+            .collect();
+        median_of_medians(&above, k - below.len() - equal)
+    }
+}
+
+// Helper function: the median by sorting, to check against
+fn sorted_median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        sorted[mid]
+    } else {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    }
+}
+
+fn main() {
+    // Test Exercise 1: NaN goes last
+    let mut values = [
+        OrderedF64(3.0),
+        OrderedF64(f64::NAN),
+        OrderedF64(-1.0),
+        OrderedF64(2.5),
+    ];
+    values.sort();
+    assert_eq!(
+        values[..3],
+        [OrderedF64(-1.0), OrderedF64(2.5), OrderedF64(3.0)]
+    );
+    assert!(values[3].0.is_nan());
+    assert_eq!(OrderedF64(f64::NAN), OrderedF64(f64::NAN));
+
+    // Test Exercise 2: median after 1, 2 and 3 insertions
+    let mut running = RunningMedian::new();
+    assert_eq!(running.median(), None);
+    running.insert(80.0);
+    assert_eq!(running.median(), Some(80.0));
+    running.insert(90.0);
+    assert_eq!(running.median(), Some(85.0));
+    running.insert(70.0);
+    assert_eq!(running.median(), Some(80.0));
+    assert_eq!(running.len(), 3);
+
+    // ...and after 100, checking the invariants at every step
+    let mut running = RunningMedian::new();
+    let mut inserted = Vec::new();
+    for i in 0..100u64 {
+        let grade = (i * 37 % 101) as f64;
+        running.insert(grade);
+        inserted.push(grade);
+
+        assert!(running.lower.len() - running.upper.len() <= 1);
+        if let (Some(low), Some(Reverse(high))) = (running.lower.peek(), running.upper.peek()) {
+            assert!(low <= high);
+        }
+        assert_eq!(running.median(), Some(sorted_median(&inserted)));
+    }
+    assert_eq!(running.len(), 100);
+    println!("Median of 100 grades: {}", running.median().unwrap());
+
+    // Test Exercise 3: every order statistic matches sorting
+    let data: Vec<f64> = (0..53u64).map(|i| (i * 29 % 53) as f64 / 2.0).collect();
+    let mut sorted = data.clone();
+    sorted.sort_by(f64::total_cmp);
+    for (k, &expected) in sorted.iter().enumerate() {
+        assert_eq!(median_of_medians(&data, k), expected);
+    }
+    // Repeated values are handled too
+    let repeated = [5.0, 1.0, 5.0, 5.0, 2.0, 5.0, 9.0, 5.0];
+    assert_eq!(median_of_medians(&repeated, 2), 5.0);
+    assert_eq!(median_of_medians(&repeated, 7), 9.0);
+    println!("Median of medians agrees with sorting for all 53 positions");
+}
@@ -0,0 +1,247 @@
+// State Machines: A trait with associated types for the state, the input
+// and the output, so each machine picks its own types and one generic
+// driver can run any of them. Parsers and protocols are full of these.
+// A circuit's gates often work the same way: they check that each row is a
+// valid step from the row before.
+
+// Exercise 1 Solution: The trait
+// There is no `self` anywhere: the machine is just its type, and all of
+// its data lives in the state.
+trait StateMachine {
+    type State: Clone + PartialEq;
+    type Input;
+    type Output;
+
+    fn initial_state() -> Self::State;
+    fn transition(state: &Self::State, input: Self::Input) -> (Self::State, Option<Self::Output>);
+    fn is_accepting(state: &Self::State) -> bool;
+}
+
+// Exercise 2 Solution: The driver
+// The machine argument only picks which implementation runs.
+fn run_machine<M: StateMachine>(_machine: &M, inputs: Vec<M::Input>) -> Vec<M::Output> {
+    let mut state = M::initial_state();
+    let mut outputs = Vec::new();
+    for input in inputs {
+        let (next, output) = M::transition(&state, input);
+        outputs.extend(output);
+        state = next;
+    }
+    outputs
+}
+
+// Exercise 3 Solution: A pocket calculator
+// Keys are pressed one at a time and applied left to right, with no
+// precedence, like a cheap calculator: "2+3*4=" gives 20.
+struct CalculatorStateMachine;
+
+#[derive(Debug, Clone, PartialEq)]
+enum CalculatorState {
+    Idle,
+    // Typing a number that will be combined with `total` using `op`
+    Number { total: i64, op: char, current: i64 },
+    // An operator was just pressed
+    Operator { total: i64, op: char },
+    Result(i64),
+}
+
+// Helper function: None for division by zero or overflow
+fn apply(total: i64, op: char, value: i64) -> Option<i64> {
+    match op {
+        '+' => total.checked_add(value),
+        '-' => total.checked_sub(value),
+        '*' => total.checked_mul(value),
+        '/' => total.checked_div(value),
+        _ => None,
+    }
+}
+
+impl StateMachine for CalculatorStateMachine {
+    type State = CalculatorState;
+    type Input = char;
+    // Ok(result) when '=' is pressed, Err(key) when a key made no sense.
+    // An error clears the calculator.
+    type Output = Result<i64, char>;
+
+    fn initial_state() -> CalculatorState {
+        CalculatorState::Idle
+    }
+
+    fn transition(
+        state: &CalculatorState,
+        key: char,
+    ) -> (CalculatorState, Option<Result<i64, char>>) {
+        use CalculatorState::*;
+
+        if key.is_whitespace() {
+            return (state.clone(), None);
+        }
+        let digit = key.to_digit(10).map(i64::from);
+        let is_operator = "+-*/".contains(key);
+
+        match (state, digit) {
+            // A digit starts a new number, or extends the current one
+            (Idle | Result(_), Some(d)) => (
+                Number {
+                    total: 0,
+                    op: '+',
+                    current: d,
+                },
+                None,
+            ),
+            (Operator { total, op }, Some(d)) => (
+                Number {
+                    total: *total,
+                    op: *op,
+                    current: d,
+                },
+                None,
+            ),
+            (&Number { total, op, current }, Some(d)) => {
+                match current.checked_mul(10).and_then(|c| c.checked_add(d)) {
+                    Some(current) => (Number { total, op, current }, None),
+                    None => (Idle, Some(Err(key))),
+                }
+            }
+
+            // An operator or '=' finishes the current number
+            (&Number { total, op, current }, None) if is_operator || key == '=' => {
+                match apply(total, op, current) {
+                    Some(total) if key == '=' => (Result(total), Some(Ok(total))),
+                    Some(total) => (Operator { total, op: key }, None),
+                    None => (Idle, Some(Err(key))),
+                }
+            }
+            // Carry on from the last result
+            (&Result(total), None) if is_operator => (Operator { total, op: key }, None),
+
+            _ => (Idle, Some(Err(key))),
+        }
+    }
+
+    fn is_accepting(state: &CalculatorState) -> bool {
+        matches!(state, CalculatorState::Result(_))
+    }
+}
+
+// Exercise 4 Solution: A lexer
+// The input is one character at a time, then None for the end of the
+// input. A token is only emitted once the next character shows it has
+// ended, which is why the end has to be marked.
+struct LexerStateMachine;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(u64),
+    Ident(String),
+    // Operators and parentheses are one character each
+    Op(char),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum LexerState {
+    Start,
+    InNumber(String),
+    InIdent(String),
+    InOp(char),
+}
+
+// Helper function: the state for a character seen between tokens
+fn start_token(c: Option<char>) -> LexerState {
+    match c {
+        Some(c) if c.is_ascii_digit() => LexerState::InNumber(c.to_string()),
+        Some(c) if c.is_alphabetic() || c == '_' => LexerState::InIdent(c.to_string()),
+        Some(c) if !c.is_whitespace() => LexerState::InOp(c),
+        _ => LexerState::Start,
+    }
+}
+
+impl StateMachine for LexerStateMachine {
+    type State = LexerState;
+    type Input = Option<char>;
+    type Output = Token;
+
+    fn initial_state() -> LexerState {
+        LexerState::Start
+    }
+
+    fn transition(state: &LexerState, input: Option<char>) -> (LexerState, Option<Token>) {
+        match (state, input) {
+            (LexerState::InNumber(digits), Some(c)) if c.is_ascii_digit() => {
+                (LexerState::InNumber(format!("{}{}", digits, c)), None)
+            }
+            (LexerState::InIdent(name), Some(c)) if c.is_alphanumeric() || c == '_' => {
+                (LexerState::InIdent(format!("{}{}", name, c)), None)
+            }
+            // Anything else ends the current token and may start the next
+            (LexerState::Start, _) => (start_token(input), None),
+            (LexerState::InNumber(digits), _) => {
+                // Only ASCII digits get here, so this fails only on overflow
+                let value = digits.parse().expect("number literal too large");
+                (start_token(input), Some(Token::Number(value)))
+            }
+            (LexerState::InIdent(name), _) => {
+                (start_token(input), Some(Token::Ident(name.clone())))
+            }
+            (&LexerState::InOp(op), _) => (start_token(input), Some(Token::Op(op))),
+        }
+    }
+
+    fn is_accepting(state: &LexerState) -> bool {
+        *state == LexerState::Start
+    }
+}
+
+// Helper function: lex a whole string, end marker included
+fn tokenize(source: &str) -> Vec<Token> {
+    let inputs = source.chars().map(Some).chain([None]).collect();
+    run_machine(&LexerStateMachine, inputs)
+}
+
+fn main() {
+    // Test Exercise 3: the calculator
+    let press = |keys: &str| run_machine(&CalculatorStateMachine, keys.chars().collect());
+    assert_eq!(press("12+30="), vec![Ok(42)]);
+    assert_eq!(press("2+3*4="), vec![Ok(20)]);
+    assert_eq!(press("7*6=-2="), vec![Ok(42), Ok(40)]);
+    assert_eq!(press("1 0 0 / 4 ="), vec![Ok(25)]);
+    assert_eq!(press("5/0="), vec![Err('=')]);
+    assert_eq!(press("+1=").first(), Some(&Err('+')));
+    println!("2+3*4= on a pocket calculator: {:?}", press("2+3*4="));
+
+    // The state machine by hand
+    let (state, output) =
+        CalculatorStateMachine::transition(&CalculatorStateMachine::initial_state(), '9');
+    assert_eq!(output, None);
+    assert!(!CalculatorStateMachine::is_accepting(&state));
+    let (state, output) = CalculatorStateMachine::transition(&state, '=');
+    assert_eq!(output, Some(Ok(9)));
+    assert!(CalculatorStateMachine::is_accepting(&state));
+
+    // Test Exercise 4: the lexer on an arithmetic expression
+    let tokens = tokenize("x1 = (42 + y)*7");
+    println!("Tokens: {:?}", tokens);
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Ident("x1".to_string()),
+            Token::Op('='),
+            Token::Op('('),
+            Token::Number(42),
+            Token::Op('+'),
+            Token::Ident("y".to_string()),
+            Token::Op(')'),
+            Token::Op('*'),
+            Token::Number(7),
+        ]
+    );
+    assert_eq!(tokenize("  "), vec![]);
+    assert_eq!(tokenize("3-"), vec![Token::Number(3), Token::Op('-')]);
+
+    // The lexer only accepts once the last token has been flushed
+    let (state, _) = LexerStateMachine::transition(&LexerState::Start, Some('4'));
+    assert!(!LexerStateMachine::is_accepting(&state));
+    let (state, token) = LexerStateMachine::transition(&state, None);
+    assert_eq!(token, Some(Token::Number(4)));
+    assert!(LexerStateMachine::is_accepting(&state));
+}
@@ -0,0 +1,224 @@
+// Validation: A trait that an exercise implements to check its own
+// solution. The exercise knows what the output should be, and a default
+// method compares that line by line with what was actually printed, so
+// every exercise gets the same checking and the same error messages for
+// free. A verifier does the same job for a proof: it checks the claimed
+// output without trusting whoever produced it.
+
+use std::fmt;
+use std::io::{self, Write};
+
+// Exercise 1 Solution: The error
+// Lines are numbered from 1. A line that is missing on one side shows up
+// as "<end of output>".
+#[derive(Debug, PartialEq)]
+struct ValidationFailed {
+    line: usize,
+    expected: String,
+    actual: String,
+}
+
+impl fmt::Display for ValidationFailed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}: expected {:?}, got {:?}",
+            self.line, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for ValidationFailed {}
+
+// Exercise 2 Solution: The trait
+trait WitnessValidator {
+    fn expected_output(&self) -> String;
+
+    // Stops at the first line that differs
+    fn validate_output(&self, output: &str) -> Result<(), ValidationFailed> {
+        let expected = self.expected_output();
+        let mut expected_lines = expected.lines();
+        let mut actual_lines = output.lines();
+        let mut line = 1;
+        loop {
+            match (expected_lines.next(), actual_lines.next()) {
+                (None, None) => return Ok(()),
+                (e, a) if e == a => line += 1,
+                (e, a) => {
+                    return Err(ValidationFailed {
+                        line,
+                        expected: e.unwrap_or("<end of output>").to_string(),
+                        actual: a.unwrap_or("<end of output>").to_string(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+// Exercise 3 Solution: Three exercises that check themselves
+// 1 to n, one per line
+struct FizzBuzzExercise {
+    n: u32,
+}
+
+impl WitnessValidator for FizzBuzzExercise {
+    fn expected_output(&self) -> String {
+        (1..=self.n)
+            .map(|i| match (i % 3, i % 5) {
+                (0, 0) => "FizzBuzz".to_string(),
+                (0, _) => "Fizz".to_string(),
+                (_, 0) => "Buzz".to_string(),
+                _ => i.to_string(),
+            })
+            .map(|line| line + "\n")
+            .collect()
+    }
+}
+
+// The first `count` Fibonacci numbers, starting 0, 1
+struct FibonacciExercise {
+    count: usize,
+}
+
+impl WitnessValidator for FibonacciExercise {
+    fn expected_output(&self) -> String {
+        std::iter::successors(Some((0u64, 1u64)), |&(a, b)| Some((b, a + b)))
+            .take(self.count)
+            .map(|(a, _)| format!("{}\n", a))
+            .collect()
+    }
+}
+
+// The answer comes with the exercise, so the checker doesn't need to know
+// how to test for primes itself
+struct PrimeCheckExercise {
+    n: i32,
+    expected: bool,
+}
+
+impl WitnessValidator for PrimeCheckExercise {
+    fn expected_output(&self) -> String {
+        if self.expected {
+            format!("{} is prime\n", self.n)
+        } else {
+            format!("{} is not prime\n", self.n)
+        }
+    }
+}
+
+// Exercise 4 Solution: Capturing the output
+// The standard library can't redirect what `println!` prints into a
+// buffer: that needs the unstable `set_output_capture` or an external
+// crate. So instead of `FnOnce()`, the solution takes the writer to print
+// to. Handed `io::stdout()` it prints as usual; handed a Vec<u8> its
+// output can be checked.
+fn run_with_capture<F: FnOnce(&mut dyn Write) -> io::Result<()>>(f: F) -> String {
+    let mut buffer = Vec::new();
+    f(&mut buffer).expect("writing to a Vec<u8> can't fail");
+    String::from_utf8(buffer).expect("solutions write text")
+}
+
+// Helper functions: one correct solution and one with an off-by-one error
+fn fizzbuzz(n: u32, out: &mut dyn Write) -> io::Result<()> {
+    for i in 1..=n {
+        match (i % 3, i % 5) {
+            (0, 0) => writeln!(out, "FizzBuzz")?,
+            (0, _) => writeln!(out, "Fizz")?,
+            (_, 0) => writeln!(out, "Buzz")?,
+            _ => writeln!(out, "{}", i)?,
+        }
+    }
+    Ok(())
+}
+
+fn fizzbuzz_off_by_one(n: u32, out: &mut dyn Write) -> io::Result<()> {
+    for i in 1..n {
+        match (i % 3, i % 5) {
+            (0, 0) => writeln!(out, "FizzBuzz")?,
+            (0, _) => writeln!(out, "Fizz")?,
+            (_, 0) => writeln!(out, "Buzz")?,
+            _ => writeln!(out, "{}", i)?,
+        }
+    }
+    Ok(())
+}
+
+// Helper function: trial division
+fn is_prime(n: i32) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+fn main() {
+    // Test Exercise 4: the correct FizzBuzz passes, through a buffer...
+    let exercise = FizzBuzzExercise { n: 15 };
+    let output = run_with_capture(|out| fizzbuzz(15, out));
+    assert_eq!(exercise.validate_output(&output), Ok(()));
+    // ...and prints the same lines when handed stdout
+    fizzbuzz(15, &mut io::stdout()).unwrap();
+
+    // The off-by-one solution stops one line early
+    let output = run_with_capture(|out| fizzbuzz_off_by_one(15, out));
+    let error = exercise.validate_output(&output).unwrap_err();
+    println!("{}", error);
+    assert_eq!(
+        error,
+        ValidationFailed {
+            line: 15,
+            expected: "FizzBuzz".to_string(),
+            actual: "<end of output>".to_string(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "line 15: expected \"FizzBuzz\", got \"<end of output>\""
+    );
+    // Too much output fails as well
+    let output = run_with_capture(|out| fizzbuzz(16, out));
+    assert_eq!(exercise.validate_output(&output).unwrap_err().line, 16);
+    // A wrong line is reported where it is
+    let error = exercise
+        .validate_output("1\n2\nFizz\n4\nFizz\n")
+        .unwrap_err();
+    println!("{}", error);
+    assert_eq!(error.line, 5);
+
+    // Test Exercise 3: Fibonacci
+    let exercise = FibonacciExercise { count: 10 };
+    assert_eq!(
+        exercise.expected_output(),
+        "0\n1\n1\n2\n3\n5\n8\n13\n21\n34\n"
+    );
+    let output = run_with_capture(|out| {
+        let (mut a, mut b) = (0u64, 1u64);
+        for _ in 0..10 {
+            writeln!(out, "{}", a)?;
+            (a, b) = (b, a + b);
+        }
+        Ok(())
+    });
+    assert_eq!(exercise.validate_output(&output), Ok(()));
+    assert_eq!(FibonacciExercise { count: 0 }.validate_output(""), Ok(()));
+
+    // Test Exercise 3: prime checks, with answers that agree with is_prime
+    for (n, expected) in [(2, true), (17, true), (1, false), (91, false), (-7, false)] {
+        assert_eq!(is_prime(n), expected);
+        let exercise = PrimeCheckExercise { n, expected };
+        let output = run_with_capture(|out| {
+            if is_prime(n) {
+                writeln!(out, "{} is prime", n)
+            } else {
+                writeln!(out, "{} is not prime", n)
+            }
+        });
+        assert_eq!(exercise.validate_output(&output), Ok(()));
+    }
+    let error = PrimeCheckExercise {
+        n: 91,
+        expected: false,
+    }
+    .validate_output("91 is prime\n")
+    .unwrap_err();
+    println!("{}", error);
+    assert_eq!(error.line, 1);
+}
@@ -0,0 +1,302 @@
+// Reed-Solomon Codes: Encode data as evaluations of a polynomial, then
+// repair the codeword after some of it has been erased or corrupted.
+//
+// Why this matters for ZK: FRI, the proximity test behind STARKs and some
+// Halo2 variants, works on exactly these codewords. A prover claims that a
+// long list of values is the evaluation of a low-degree polynomial, which
+// is the same as saying it is a Reed-Solomon codeword. Two different
+// low-degree polynomials agree on very few points, so a list that is *not*
+// a codeword must be far from every codeword, and a verifier can catch it
+// by checking a handful of random positions.
+//
+// All arithmetic is done modulo a prime, so every non-zero value has an
+// inverse and polynomial interpolation always works.
+
+// Helper functions for arithmetic modulo a prime
+fn mod_add(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 + b as u128) % p as u128) as u64
+}
+
+fn mod_sub(a: u64, b: u64, p: u64) -> u64 {
+    mod_add(a, p - b % p, p)
+}
+
+fn mod_mul(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 * b as u128) % p as u128) as u64
+}
+
+fn mod_pow(mut base: u64, mut exp: u64, p: u64) -> u64 {
+    let mut result = 1;
+    base %= p;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, p);
+        }
+        base = mod_mul(base, base, p);
+        exp >>= 1;
+    }
+    result
+}
+
+// Fermat's little theorem: a^(p-2) is the inverse of a modulo a prime p
+fn mod_inv(a: u64, p: u64) -> u64 {
+    assert!(!a.is_multiple_of(p), "zero has no inverse");
+    mod_pow(a, p - 2, p)
+}
+
+// Horner's method: c0 + x * (c1 + x * (c2 + ...))
+fn evaluate(coeffs: &[u64], x: u64, p: u64) -> u64 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(0, |acc, &c| mod_add(mod_mul(acc, x, p), c, p))
+}
+
+// Exercise 1 Solution: Encoding
+// The data are the coefficients of a polynomial of degree < data.len().
+// Evaluating it at data.len() + n_check points adds redundancy: any
+// data.len() correct evaluations are enough to get the polynomial back.
+// In FRI terms the "rate" of this code is data.len() / (data.len() + n_check).
+fn encode_rs_simple(data: &[u64], n_check: usize, prime: u64) -> Vec<u64> {
+    let n = data.len() + n_check;
+    assert!(
+        (n as u64) < prime,
+        "need {} distinct points modulo {}",
+        n,
+        prime
+    );
+    (0..n as u64).map(|x| evaluate(data, x, prime)).collect()
+}
+
+// Exercise 2 Solution: Erasure correction
+// Erasures are symbols we know are missing. Every other symbol is a
+// correct evaluation, so Lagrange interpolation through the known points
+// gives back the value at each missing point.
+//
+// Precondition: at most n_check positions are erased, i.e. at least
+// data_len symbols are still known. With fewer known points the
+// interpolated polynomial has too low a degree, and the values written
+// back are silently wrong.
+fn correct_erasures(received: &mut [u64], erasure_positions: &[usize], prime: u64) {
+    let known: Vec<(u64, u64)> = (0..received.len())
+        .filter(|i| !erasure_positions.contains(i))
+        .map(|i| (i as u64, received[i]))
+        .collect();
+
+    for &position in erasure_positions {
+        let x = position as u64;
+        let mut value = 0;
+        for (j, &(xj, yj)) in known.iter().enumerate() {
+            // L_j(x) = prod over m != j of (x - x_m) / (x_j - x_m)
+            let mut numerator = 1;
+            let mut denominator = 1;
+            for (m, &(xm, _)) in known.iter().enumerate() {
+                if m != j {
+                    numerator = mod_mul(numerator, mod_sub(x, xm, prime), prime);
+                    denominator = mod_mul(denominator, mod_sub(xj, xm, prime), prime);
+                }
+            }
+            let basis = mod_mul(numerator, mod_inv(denominator, prime), prime);
+            value = mod_add(value, mod_mul(yj, basis, prime), prime);
+        }
+        received[position] = value;
+    }
+}
+
+// Solve a linear system modulo a prime with Gauss-Jordan elimination.
+// Free variables are set to zero; returns None if the system has no solution.
+fn solve_linear_system(mut rows: Vec<Vec<u64>>, p: u64) -> Option<Vec<u64>> {
+    let n_vars = rows.first().map_or(0, |row| row.len() - 1);
+    let mut pivot_cols = Vec::new();
+    let mut rank = 0;
+
+    for col in 0..n_vars {
+        let Some(pivot) = (rank..rows.len()).find(|&r| rows[r][col] != 0) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+
+        let inv = mod_inv(rows[rank][col], p);
+        for value in rows[rank].iter_mut() {
+            *value = mod_mul(*value, inv, p);
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && row[col] != 0 {
+                let factor = row[col];
+                for (value, &pivot_value) in row.iter_mut().zip(&pivot_row) {
+                    *value = mod_sub(*value, mod_mul(factor, pivot_value, p), p);
+                }
+            }
+        }
+        pivot_cols.push(col);
+        rank += 1;
+    }
+
+    // A row reading 0 = non-zero means there is no solution
+    if rows[rank..].iter().any(|row| row[n_vars] != 0) {
+        return None;
+    }
+
+    let mut solution = vec![0; n_vars];
+    for (row, &col) in pivot_cols.iter().enumerate() {
+        solution[col] = rows[row][n_vars];
+    }
+    Some(solution)
+}
+
+// Polynomial long division, returning (quotient, remainder)
+fn poly_div(numerator: &[u64], denominator: &[u64], p: u64) -> (Vec<u64>, Vec<u64>) {
+    let mut remainder = numerator.to_vec();
+    let d = denominator.len() - 1;
+    let lead_inv = mod_inv(denominator[d], p);
+    let mut quotient = vec![0; numerator.len().saturating_sub(d).max(1)];
+
+    for i in (d..numerator.len()).rev() {
+        let coeff = mod_mul(remainder[i], lead_inv, p);
+        quotient[i - d] = coeff;
+        for (j, &dj) in denominator.iter().enumerate() {
+            let delta = mod_mul(coeff, dj, p);
+            remainder[i - d + j] = mod_sub(remainder[i - d + j], delta, p);
+        }
+    }
+    remainder.truncate(d);
+    (quotient, remainder)
+}
+
+// Exercise 3 Solution: Decoding with errors (Berlekamp-Welch)
+// Errors are harder than erasures because we don't know where they are.
+// Berlekamp-Massey is the classic answer for codes built from syndromes;
+// for our evaluation-style code the simpler Berlekamp-Welch algorithm does
+// the same job. Let E(x) be the "error locator", a polynomial that is zero
+// at every corrupted position, and let Q(x) = P(x) * E(x). Then for every
+// point, corrupted or not,
+//     Q(x_i) = y_i * E(x_i)
+// which is a linear system in the coefficients of Q and E. Solve it, and
+// P = Q / E. This works as long as at most n_check / 2 symbols are wrong.
+fn decode_polynomial(received: &[u64], data_len: usize, prime: u64) -> Option<Vec<u64>> {
+    if received.len() < data_len {
+        return None;
+    }
+    let max_errors = (received.len() - data_len) / 2;
+    let q_len = data_len + max_errors;
+
+    // Unknowns: q_0..q_{q_len-1}, then e_0..e_{max_errors-1}.
+    // E is monic, so its top coefficient moves to the right-hand side.
+    let rows: Vec<Vec<u64>> = received
+        .iter()
+        .enumerate()
+        .map(|(i, &y)| {
+            let x = i as u64;
+            let mut row: Vec<u64> = (0..q_len).map(|j| mod_pow(x, j as u64, prime)).collect();
+            row.extend(
+                (0..max_errors)
+                    .map(|j| mod_sub(0, mod_mul(y, mod_pow(x, j as u64, prime), prime), prime)),
+            );
+            row.push(mod_mul(y, mod_pow(x, max_errors as u64, prime), prime));
+            row
+        })
+        .collect();
+
+    let solution = solve_linear_system(rows, prime)?;
+    let q = &solution[..q_len];
+    let mut e = solution[q_len..].to_vec();
+    e.push(1);
+
+    let (mut p_coeffs, remainder) = poly_div(q, &e, prime);
+    if remainder.iter().any(|&r| r != 0) {
+        return None;
+    }
+    p_coeffs.resize(data_len, 0);
+    Some(p_coeffs)
+}
+
+// Exercise 4 Solution: Locating errors
+// Decode, re-encode, and report every position that disagrees. The
+// decoding is Berlekamp-Welch from Exercise 3, not Berlekamp-Massey.
+// Returns None when too many symbols are corrupted to find a unique
+// polynomial.
+fn try_detect_errors(received: &[u64], data_len: usize, prime: u64) -> Option<Vec<usize>> {
+    if received.len() < data_len {
+        return None;
+    }
+    let poly = decode_polynomial(received, data_len, prime)?;
+    let errors: Vec<usize> = received
+        .iter()
+        .enumerate()
+        .filter(|&(i, &y)| evaluate(&poly, i as u64, prime) != y)
+        .map(|(i, _)| i)
+        .collect();
+
+    // A "decoded" polynomial that needs more fixes than the code allows is
+    // just a different codeword we happened to land near
+    if errors.len() > (received.len() - data_len) / 2 {
+        return None;
+    }
+    Some(errors)
+}
+
+// An empty Vec already means "no errors", so an undecodable word can't be
+// reported that way. Panics if more than n_check / 2 symbols are wrong;
+// use try_detect_errors when that can happen.
+fn detect_errors(received: &[u64], data_len: usize, prime: u64) -> Vec<usize> {
+    try_detect_errors(received, data_len, prime)
+        .expect("too many errors to decode: more than n_check / 2 symbols are wrong")
+}
+
+fn main() {
+    let prime = 65537;
+    let data = [42, 7, 1000, 3, 65000];
+    let n_check = 6;
+
+    // Test Exercise 1: the codeword starts with P(0) = data[0]
+    let codeword = encode_rs_simple(&data, n_check, prime);
+    assert_eq!(codeword.len(), data.len() + n_check);
+    assert_eq!(codeword[0], data[0]);
+    println!("Codeword: {:?}", codeword);
+
+    // Test Exercise 2: up to n_check erasures can be filled in
+    let erased = [0, 2, 3, 5, 8, 10];
+    let mut received = codeword.clone();
+    for &i in &erased {
+        received[i] = 0;
+    }
+    correct_erasures(&mut received, &erased, prime);
+    assert_eq!(received, codeword);
+
+    // Test Exercise 3 and 4: up to n_check / 2 errors are found and fixed
+    for n_errors in 0..=n_check / 2 {
+        let mut received = codeword.clone();
+        let positions: Vec<usize> = (0..n_errors)
+            .map(|k| (3 * k + 1) % codeword.len())
+            .collect();
+        for &i in &positions {
+            received[i] = mod_add(received[i], 12345, prime);
+        }
+        println!("{} error(s) at {:?}", n_errors, positions);
+
+        let mut found = detect_errors(&received, data.len(), prime);
+        found.sort();
+        assert_eq!(found, positions);
+
+        correct_erasures(&mut received, &found, prime);
+        assert_eq!(received, codeword);
+        assert_eq!(
+            decode_polynomial(&received, data.len(), prime).unwrap(),
+            data
+        );
+    }
+
+    // One error too many: the decoder must not claim success
+    let mut received = codeword.clone();
+    for i in [0, 1, 2, 3] {
+        received[i] = mod_add(received[i], i as u64 + 1, prime);
+    }
+    assert_eq!(try_detect_errors(&received, data.len(), prime), None);
+
+    // A received word shorter than the data can't be decoded at all
+    assert_eq!(decode_polynomial(&codeword[..3], data.len(), prime), None);
+    assert_eq!(try_detect_errors(&codeword[..3], data.len(), prime), None);
+
+    println!("All error correction exercises passed!");
+}
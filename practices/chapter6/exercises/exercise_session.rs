@@ -0,0 +1,259 @@
+// Exercise Session: Remember which practice exercises are done, save that
+// progress to a file and read it back. Reading a file can fail in two very
+// different ways: the file can't be read at all, or it can be read but
+// doesn't make sense. SessionError keeps those apart, and `?` with a From
+// impl turns the first kind into a SessionError without any extra code.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Exercise 1 Solution: The exercises and what each one builds on
+// In the order they are meant to be done
+fn all_chapter_exercises() -> Vec<(&'static str, Vec<&'static str>)> {
+    vec![
+        ("chapter2/variables", vec![]),
+        ("chapter2/functions", vec!["chapter2/variables"]),
+        ("chapter2/control_flow", vec!["chapter2/functions"]),
+        ("chapter2/integration", vec!["chapter2/control_flow"]),
+        ("chapter3/bitwise", vec!["chapter2/integration"]),
+        ("chapter3/recursion", vec!["chapter2/integration"]),
+        ("chapter3/custom_iterators", vec!["chapter3/recursion"]),
+        ("chapter4/field_arithmetic", vec!["chapter3/bitwise"]),
+        ("chapter4/square_roots", vec!["chapter4/field_arithmetic"]),
+        ("chapter5/rose_tree", vec!["chapter3/custom_iterators"]),
+        ("chapter5/state_machine", vec!["chapter5/rose_tree"]),
+        ("chapter6/strict_parser", vec!["chapter5/state_machine"]),
+        (
+            "chapter6/error_correction",
+            vec!["chapter4/field_arithmetic"],
+        ),
+        ("chapter7/phantom_types", vec!["chapter5/rose_tree"]),
+        ("chapter8/anti_patterns", vec!["chapter6/strict_parser"]),
+        ("chapter9/parallel_merkle", vec!["chapter7/phantom_types"]),
+        ("chapter10/async_prover", vec!["chapter9/parallel_merkle"]),
+        ("chapter11/ffi", vec!["chapter10/async_prover"]),
+    ]
+}
+
+// Exercise 2 Solution: What can go wrong when loading
+#[derive(Debug)]
+enum SessionError {
+    Io(io::Error),
+    // A line that isn't `key=value`, with its line number
+    MalformedLine { line: usize, content: String },
+    InvalidValue { key: String, value: String },
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionError::Io(error) => write!(f, "could not read the session file: {}", error),
+            SessionError::MalformedLine { line, content } => {
+                write!(f, "line {}: expected key=value, found {:?}", line, content)
+            }
+            SessionError::InvalidValue { key, value } => {
+                write!(f, "invalid value {:?} for {}", value, key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+impl From<io::Error> for SessionError {
+    fn from(error: io::Error) -> Self {
+        SessionError::Io(error)
+    }
+}
+
+// Exercise 3 Solution: The session
+// Times are seconds since the Unix epoch
+#[derive(Debug, PartialEq)]
+struct ExerciseSession {
+    completed: HashSet<String>,
+    scores: HashMap<String, u8>,
+    session_start: u64,
+    total_time_secs: u64,
+}
+
+impl ExerciseSession {
+    fn new() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        ExerciseSession {
+            completed: HashSet::new(),
+            scores: HashMap::new(),
+            session_start: now,
+            total_time_secs: 0,
+        }
+    }
+
+    // Doing an exercise again keeps the better score
+    fn mark_complete(&mut self, exercise_id: &str, score: u8) {
+        self.completed.insert(exercise_id.to_string());
+        let best = self.scores.entry(exercise_id.to_string()).or_insert(score);
+        *best = (*best).max(score);
+    }
+
+    fn is_complete(&self, exercise_id: &str) -> bool {
+        self.completed.contains(exercise_id)
+    }
+
+    fn completion_percentage(&self) -> f64 {
+        let exercises = all_chapter_exercises();
+        let done = exercises
+            .iter()
+            .filter(|(id, _)| self.is_complete(id))
+            .count();
+        100.0 * done as f64 / exercises.len() as f64
+    }
+
+    // The first exercise not done yet whose prerequisites all are
+    fn recommended_next(&self) -> Option<&str> {
+        all_chapter_exercises()
+            .into_iter()
+            .find(|(id, prerequisites)| {
+                !self.is_complete(id) && prerequisites.iter().all(|p| self.is_complete(p))
+            })
+            .map(|(id, _)| id)
+    }
+
+    // Exercise 4 Solution: Saving and loading
+    // One `key=value` per line. Completed exercises are `completed=<id>`
+    // and scores are `score.<id>=<score>`; lines are sorted so the same
+    // session always gives the same file.
+    fn save_to_file(&self, path: &str) -> io::Result<()> {
+        let mut lines = vec![
+            format!("session_start={}", self.session_start),
+            format!("total_time_secs={}", self.total_time_secs),
+        ];
+        let mut completed: Vec<&String> = self.completed.iter().collect();
+        completed.sort();
+        lines.extend(completed.iter().map(|id| format!("completed={}", id)));
+        let mut scores: Vec<(&String, &u8)> = self.scores.iter().collect();
+        scores.sort();
+        lines.extend(
+            scores
+                .iter()
+                .map(|(id, score)| format!("score.{}={}", id, score)),
+        );
+        fs::write(path, lines.join("\n") + "\n")
+    }
+
+    fn load_from_file(path: &str) -> Result<Self, SessionError> {
+        let contents = fs::read_to_string(path)?;
+        let mut session = ExerciseSession {
+            completed: HashSet::new(),
+            scores: HashMap::new(),
+            session_start: 0,
+            total_time_secs: 0,
+        };
+
+        for (number, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(SessionError::MalformedLine {
+                    line: number + 1,
+                    content: line.to_string(),
+                });
+            };
+            let invalid = || SessionError::InvalidValue {
+                key: key.to_string(),
+                value: value.to_string(),
+            };
+
+            match key {
+                "session_start" => session.session_start = value.parse().map_err(|_| invalid())?,
+                "total_time_secs" => {
+                    session.total_time_secs = value.parse().map_err(|_| invalid())?
+                }
+                "completed" => {
+                    session.completed.insert(value.to_string());
+                }
+                _ => match key.strip_prefix("score.") {
+                    Some(id) => {
+                        let score = value.parse().map_err(|_| invalid())?;
+                        session.scores.insert(id.to_string(), score);
+                    }
+                    None => {
+                        return Err(SessionError::MalformedLine {
+                            line: number + 1,
+                            content: line.to_string(),
+                        })
+                    }
+                },
+            }
+        }
+        Ok(session)
+    }
+}
+
+fn main() {
+    // Test Exercise 1: every prerequisite is an exercise listed earlier
+    let exercises = all_chapter_exercises();
+    for (i, (id, prerequisites)) in exercises.iter().enumerate() {
+        for p in prerequisites {
+            assert!(
+                exercises[..i].iter().any(|(earlier, _)| earlier == p),
+                "{} needs {}, which comes later",
+                id,
+                p
+            );
+        }
+    }
+
+    // Test Exercise 3
+    let mut session = ExerciseSession::new();
+    assert_eq!(session.recommended_next(), Some("chapter2/variables"));
+    session.mark_complete("chapter2/variables", 80);
+    session.mark_complete("chapter2/variables", 95);
+    session.mark_complete("chapter2/variables", 70);
+    session.mark_complete("chapter2/functions", 88);
+    assert!(session.is_complete("chapter2/functions"));
+    assert!(!session.is_complete("chapter2/control_flow"));
+    assert_eq!(session.scores["chapter2/variables"], 95);
+    assert_eq!(session.recommended_next(), Some("chapter2/control_flow"));
+    let percentage = session.completion_percentage();
+    println!(
+        "{:.1}% complete, next up: {:?}",
+        percentage,
+        session.recommended_next()
+    );
+    assert!((percentage - 200.0 / exercises.len() as f64).abs() < 1e-9);
+
+    // Test Exercise 4: a saved session loads back exactly
+    session.total_time_secs = 5400;
+    let path = std::env::temp_dir().join(format!("exercise_session_{}.txt", std::process::id()));
+    let path = path.to_str().unwrap();
+    session.save_to_file(path).unwrap();
+    let contents = fs::read_to_string(path).unwrap();
+    println!("Saved session:\n{}", contents);
+    let loaded = ExerciseSession::load_from_file(path).unwrap();
+    assert_eq!(loaded, session);
+    assert_eq!(loaded.recommended_next(), session.recommended_next());
+    // The same session always writes the same file
+    loaded.save_to_file(path).unwrap();
+    assert_eq!(fs::read_to_string(path).unwrap(), contents);
+
+    // Test Exercise 2: each kind of error
+    fs::write(path, "session_start=12\nthis line has no equals sign\n").unwrap();
+    let error = ExerciseSession::load_from_file(path).unwrap_err();
+    println!("{}", error);
+    assert!(matches!(error, SessionError::MalformedLine { line: 2, .. }));
+
+    fs::write(path, "score.chapter2/variables=300\n").unwrap();
+    let error = ExerciseSession::load_from_file(path).unwrap_err();
+    println!("{}", error);
+    assert!(matches!(error, SessionError::InvalidValue { .. }));
+
+    fs::remove_file(path).unwrap();
+    let error = ExerciseSession::load_from_file(path).unwrap_err();
+    println!("{}", error);
+    assert!(matches!(error, SessionError::Io(_)));
+}
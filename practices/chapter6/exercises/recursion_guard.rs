@@ -0,0 +1,172 @@
+// Recursion Guard: In recursive proof composition a proof verifies other
+// proofs inside its circuit, which may verify others in turn. A bug in how
+// that tree is built can make it loop forever or grow without bound. The
+// guard below turns both into errors: it caps the nesting depth and
+// refuses to enter a proof that is already being proven further up.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+// Exercise 1 Solution: The errors
+#[derive(Debug, PartialEq)]
+enum RecursionError {
+    // The limit that would have been passed
+    MaxDepthExceeded(usize),
+    // A proof that is already on the current path
+    CircularProof(u64),
+}
+
+impl fmt::Display for RecursionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecursionError::MaxDepthExceeded(limit) => {
+                write!(f, "recursion deeper than the limit of {}", limit)
+            }
+            RecursionError::CircularProof(id) => {
+                write!(f, "proof {:#018x} depends on itself", id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecursionError {}
+
+// Exercise 2 Solution: The guard
+// `proof_ids` holds the proofs on the current path from the root, so the
+// same sub-proof may appear twice side by side, but never inside itself.
+struct RecursionGuard {
+    max_depth: usize,
+    current_depth: usize,
+    proof_ids: HashSet<u64>,
+}
+
+impl RecursionGuard {
+    fn new(max_depth: usize) -> Self {
+        RecursionGuard {
+            max_depth,
+            current_depth: 0,
+            proof_ids: HashSet::new(),
+        }
+    }
+
+    // Nested proofs need to enter the guard again, but `self` is already
+    // borrowed mutably for the duration of the call, so `f` is handed the
+    // guard instead of capturing it. If `f` panics, the guard is left as
+    // it was mid-proof.
+    fn enter<R, F: FnOnce(&mut RecursionGuard) -> R>(
+        &mut self,
+        proof_id: u64,
+        f: F,
+    ) -> Result<R, RecursionError> {
+        if self.current_depth >= self.max_depth {
+            return Err(RecursionError::MaxDepthExceeded(self.max_depth));
+        }
+        if !self.proof_ids.insert(proof_id) {
+            return Err(RecursionError::CircularProof(proof_id));
+        }
+
+        self.current_depth += 1;
+        let result = f(self);
+        self.current_depth -= 1;
+        self.proof_ids.remove(&proof_id);
+        Ok(result)
+    }
+}
+
+// Exercise 3 Solution: Identifying a proof
+// Helper types: just enough of a circuit for this exercise
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+struct FieldElement(u64);
+
+// Each gate reads two earlier wires; wire i < num_inputs is input i
+#[derive(Debug, Hash)]
+enum Gate {
+    Add(usize, usize),
+    Mul(usize, usize),
+}
+
+#[derive(Debug, Hash)]
+struct ArithmeticCircuit {
+    num_inputs: usize,
+    gates: Vec<Gate>,
+}
+
+// The same circuit on the same inputs is the same proof. DefaultHasher is
+// fine for spotting repeats, but it is not collision resistant, so it
+// would be no good as a commitment to the circuit.
+fn compute_proof_id(circuit: &ArithmeticCircuit, inputs: &[FieldElement]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    circuit.hash(&mut hasher);
+    inputs.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Helper function: prove a chain of nested proofs, the first one
+// outermost, and report the deepest depth reached
+fn prove_chain(guard: &mut RecursionGuard, ids: &[u64]) -> Result<usize, RecursionError> {
+    match ids.split_first() {
+        None => Ok(guard.current_depth),
+        Some((&id, rest)) => guard.enter(id, |guard| prove_chain(guard, rest))?,
+    }
+}
+
+fn main() {
+    // Test Exercise 2: a depth limit of 3 lets depth 3 through...
+    let mut guard = RecursionGuard::new(3);
+    assert_eq!(prove_chain(&mut guard, &[1, 2, 3]), Ok(3));
+    // ...and blocks depth 4
+    let error = prove_chain(&mut guard, &[1, 2, 3, 4]).unwrap_err();
+    println!("{}", error);
+    assert_eq!(error, RecursionError::MaxDepthExceeded(3));
+
+    // A proof that turns up inside itself is circular
+    let error = prove_chain(&mut RecursionGuard::new(10), &[7, 8, 7]).unwrap_err();
+    println!("{}", error);
+    assert_eq!(error, RecursionError::CircularProof(7));
+
+    // Successful proofs hand back their depth and their ids
+    let mut guard = RecursionGuard::new(3);
+    let inner_depth = guard.enter(1, |guard| guard.current_depth).unwrap();
+    assert_eq!(inner_depth, 1);
+    assert_eq!(guard.current_depth, 0);
+    assert!(guard.proof_ids.is_empty());
+    // so the same sub-proof can be used twice side by side
+    let siblings = guard.enter(1, |guard| {
+        let left = guard.enter(2, |_| "left");
+        let right = guard.enter(2, |_| "right");
+        (left, right)
+    });
+    assert_eq!(siblings, Ok((Ok("left"), Ok("right"))));
+    // Failed attempts leave the guard as they found it too
+    assert!(prove_chain(&mut guard, &[5, 6, 5]).is_err());
+    assert!(prove_chain(&mut guard, &[1, 2, 3, 4]).is_err());
+    assert_eq!(guard.current_depth, 0);
+    assert!(guard.proof_ids.is_empty());
+
+    // Test Exercise 3: ids depend on the circuit and on the inputs
+    // (x + y) * y
+    let circuit = ArithmeticCircuit {
+        num_inputs: 2,
+        gates: vec![Gate::Add(0, 1), Gate::Mul(2, 1)],
+    };
+    let inputs = [FieldElement(3), FieldElement(4)];
+    let id = compute_proof_id(&circuit, &inputs);
+    println!("Proof id: {:#018x}", id);
+    assert_eq!(id, compute_proof_id(&circuit, &inputs));
+    assert_ne!(
+        id,
+        compute_proof_id(&circuit, &[FieldElement(4), FieldElement(3)])
+    );
+    let other = ArithmeticCircuit {
+        num_inputs: 2,
+        gates: vec![Gate::Add(0, 1), Gate::Add(2, 1)],
+    };
+    assert_ne!(id, compute_proof_id(&other, &inputs));
+
+    // A recursive proof that verifies itself is caught by its id
+    let mut guard = RecursionGuard::new(5);
+    let result = guard.enter(id, |guard| guard.enter(id, |_| ()));
+    assert_eq!(result, Ok(Err(RecursionError::CircularProof(id))));
+}
@@ -0,0 +1,158 @@
+// SHA-256 Padding: SHA-256 works on 64-byte blocks, so every message is
+// padded first: a single 0x80 byte, then zeros, then the message length in
+// bits as a 64-bit big-endian number, so that the total is a multiple of
+// 64. Undoing that padding is a good exercise in error handling, because
+// there are several different ways for it to be wrong.
+//
+// Why padding schemes differ:
+// - SHA-256 (Merkle-Damgard strengthening) puts the length at the end, so
+//   messages of different lengths always differ in their last block; that
+//   is what lets a collision-resistant compression function give a
+//   collision-resistant hash. It does NOT stop length extension: knowing
+//   H(m) lets anyone compute H(m || pad || x) without knowing m, which is
+//   why keyed hashing uses HMAC, not H(key || m).
+// - PKCS#7 pads block-cipher input with N copies of the byte N. If a
+//   server answers "bad padding" differently from "bad data", that answer
+//   becomes a padding oracle, and repeated guesses decrypt the whole
+//   ciphertext (Vaudenay's attack). Errors must all look the same.
+// - PSS pads RSA signatures with a random salt and a mask, so signing the
+//   same message twice gives different signatures. Plain "textbook" RSA
+//   is malleable, and PSS is what makes its security provable.
+// In a circuit, SHA-256 padding is checked with constraints too.
+// Otherwise a prover could pick a padding that makes two different
+// preimages look like the same message.
+
+use std::fmt;
+
+// Exercise 1 Solution: Padding a message
+fn sha256_pad(message: &[u8]) -> Vec<u8> {
+    let bit_length = (message.len() as u64) * 8;
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    // Leave exactly 8 bytes at the end of the last block for the length
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&bit_length.to_be_bytes());
+    padded
+}
+
+// Exercise 2 Solution: What can go wrong
+// Lengths are in bits, as they are in the padding itself.
+#[derive(Debug, PartialEq)]
+enum PaddingError {
+    // Too short, or not a whole number of 64-byte blocks
+    TruncatedPadding,
+    // The last non-zero byte before the length isn't 0x80
+    InvalidTerminator(u8),
+    // The length field disagrees with where the message ends
+    LengthMismatch { encoded: u64, actual: u64 },
+}
+
+impl fmt::Display for PaddingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PaddingError::TruncatedPadding => {
+                write!(f, "padded input must be a non-empty multiple of 64 bytes")
+            }
+            PaddingError::InvalidTerminator(byte) => {
+                write!(f, "expected the 0x80 terminator, found {:#04x}", byte)
+            }
+            PaddingError::LengthMismatch { encoded, actual } => write!(
+                f,
+                "length field says {} bits, but the message is {} bits",
+                encoded, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PaddingError {}
+
+// Exercise 3 Solution: Removing the padding
+// Read the length, then walk back over the zeros to the terminator. At
+// most 63 zero bytes are ever needed, so a 64th one means the padding is
+// malformed instead of just long.
+fn validate_padding(padded: &[u8]) -> Result<Vec<u8>, PaddingError> {
+    if padded.is_empty() || !padded.len().is_multiple_of(64) {
+        return Err(PaddingError::TruncatedPadding);
+    }
+    let (body, length) = padded.split_at(padded.len() - 8);
+    let encoded = u64::from_be_bytes(length.try_into().unwrap());
+
+    let zeros = body.iter().rev().take(64).take_while(|&&b| b == 0).count();
+    let Some(terminator) = body.len().checked_sub(zeros + 1) else {
+        return Err(PaddingError::TruncatedPadding);
+    };
+    match body[terminator] {
+        0x80 => {}
+        byte => return Err(PaddingError::InvalidTerminator(byte)),
+    }
+
+    let actual = terminator as u64 * 8;
+    if encoded != actual {
+        return Err(PaddingError::LengthMismatch { encoded, actual });
+    }
+    Ok(body[..terminator].to_vec())
+}
+
+fn main() {
+    // Test Exercise 1: the padding for "abc" from the SHA-256 standard
+    let padded = sha256_pad(b"abc");
+    assert_eq!(padded.len(), 64);
+    assert_eq!(&padded[..4], &[b'a', b'b', b'c', 0x80]);
+    assert!(padded[4..56].iter().all(|&b| b == 0));
+    assert_eq!(&padded[56..], &24u64.to_be_bytes());
+
+    // Test Exercise 3: every length from 0 to 128 bytes round-trips, and
+    // uses the fewest blocks that fit the message plus 9 bytes
+    for length in 0..=128usize {
+        let message: Vec<u8> = (0..length).map(|i| (i * 7 + 1) as u8).collect();
+        let padded = sha256_pad(&message);
+        assert_eq!(padded.len() % 64, 0);
+        assert_eq!(padded.len(), (length + 9).div_ceil(64) * 64);
+        assert_eq!(validate_padding(&padded), Ok(message));
+    }
+    // A message that itself ends in 0x80 or zeros is still recovered
+    let tricky = [1, 0x80, 0, 0];
+    assert_eq!(validate_padding(&sha256_pad(&tricky)), Ok(tricky.to_vec()));
+    println!("Messages of 0 to 128 bytes all round-trip");
+
+    // Test Exercise 2: each error variant
+    assert_eq!(validate_padding(&[]), Err(PaddingError::TruncatedPadding));
+    assert_eq!(
+        validate_padding(&padded[..63]),
+        Err(PaddingError::TruncatedPadding)
+    );
+
+    let mut bad = sha256_pad(b"abc");
+    bad[3] = 0x81;
+    let error = validate_padding(&bad).unwrap_err();
+    println!("{}", error);
+    assert_eq!(error, PaddingError::InvalidTerminator(0x81));
+
+    // Too many zeros: the terminator would have to be 64+ bytes back
+    let mut bad = vec![0u8; 128];
+    bad[0] = 0x80;
+    assert_eq!(
+        validate_padding(&bad),
+        Err(PaddingError::InvalidTerminator(0))
+    );
+    // No terminator at all
+    assert_eq!(
+        validate_padding(&[0u8; 64]),
+        Err(PaddingError::TruncatedPadding)
+    );
+
+    let mut bad = sha256_pad(b"abc");
+    bad[63] = 16;
+    let error = validate_padding(&bad).unwrap_err();
+    println!("{}", error);
+    assert_eq!(
+        error,
+        PaddingError::LengthMismatch {
+            encoded: 16,
+            actual: 24
+        }
+    );
+}
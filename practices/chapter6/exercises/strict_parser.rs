@@ -0,0 +1,227 @@
+// Strict Parser: A calculator parser that refuses malformed input and says
+// exactly where and why. Every error carries the position it happened at,
+// the character it found there, and what would have been valid instead.
+//
+// Grammar, from lowest to highest precedence:
+//     expression = term (('+' | '-') term)*
+//     term       = factor (('*' | '/') factor)*
+//     factor     = number | '(' expression ')' | '-' factor
+
+use std::fmt;
+
+// Exercise 1 Solution: A descriptive error
+#[derive(Debug, PartialEq)]
+struct ParseError {
+    position: usize,
+    found: Option<char>,
+    expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Error at position {}: expected {}, found ",
+            self.position, self.expected
+        )?;
+        match self.found {
+            Some(c) => write!(f, "'{}'", c),
+            None => write!(f, "end of input"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Exercise 2 Solution: The parser
+// `position` is a byte offset into `input`, so slicing at it is always
+// valid as long as we only ever step over whole characters.
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser { input, position: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn consume(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        ParseError {
+            position: self.position,
+            found: self.peek(),
+            expected: expected.to_string(),
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.consume();
+            Ok(())
+        } else {
+            Err(self.error(&format!("'{}'", c)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.consume();
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<f64, ParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.consume();
+        }
+        if self.peek() == Some('.') {
+            self.consume();
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.consume();
+            }
+        }
+
+        let text = &self.input[start..self.position];
+        text.parse().map_err(|_| {
+            // Report the error where the number should have started
+            self.position = start;
+            self.error("a number")
+        })
+    }
+
+    // Exercise 3 Solution: The grammar, one method per rule
+    fn parse_sum(&mut self) -> Result<f64, ParseError> {
+        let mut value = self.parse_product()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('+') => {
+                    self.consume();
+                    value += self.parse_product()?;
+                }
+                Some('-') => {
+                    self.consume();
+                    value -= self.parse_product()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn parse_product(&mut self) -> Result<f64, ParseError> {
+        let mut value = self.parse_factor()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('*') => {
+                    self.consume();
+                    value *= self.parse_factor()?;
+                }
+                Some('/') => {
+                    self.consume();
+                    value /= self.parse_factor()?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn parse_factor(&mut self) -> Result<f64, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('(') => {
+                self.consume();
+                let value = self.parse_sum()?;
+                self.expect(')')?;
+                Ok(value)
+            }
+            Some('-') => {
+                self.consume();
+                Ok(-self.parse_factor()?)
+            }
+            _ => self.parse_number(),
+        }
+    }
+}
+
+// Anything left over after a complete expression is an error too, so
+// "1 2" doesn't quietly evaluate to 1
+fn parse_expression(input: &str) -> Result<f64, ParseError> {
+    let mut parser = Parser::new(input);
+    let value = parser.parse_sum()?;
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.error("'+', '-', '*', or '/'"));
+    }
+    Ok(value)
+}
+
+fn main() {
+    // Test Exercise 2: the building blocks
+    let mut parser = Parser::new("  4.5)");
+    assert_eq!(parser.parse_number(), Ok(4.5));
+    assert_eq!(parser.peek(), Some(')'));
+    assert_eq!(parser.expect(')'), Ok(()));
+    assert_eq!(parser.consume(), None);
+
+    // Test Exercise 3: valid expressions
+    assert_eq!(parse_expression("1 + 2 * 3"), Ok(7.0));
+    assert_eq!(parse_expression("(1 + 2) * 3"), Ok(9.0));
+    assert_eq!(parse_expression("10 / 4 - -1"), Ok(3.5));
+    assert_eq!(parse_expression(" 2 * (3 + (4 - 1)) "), Ok(12.0));
+
+    // The example message from the spec
+    let error = parse_expression("12 x 3").unwrap_err();
+    println!("{}", error);
+    assert_eq!(
+        error.to_string(),
+        "Error at position 3: expected '+', '-', '*', or '/', found 'x'"
+    );
+
+    // Missing operand
+    let error = parse_expression("3 +").unwrap_err();
+    println!("{}", error);
+    assert_eq!(
+        error,
+        ParseError {
+            position: 3,
+            found: None,
+            expected: "a number".to_string(),
+        }
+    );
+
+    // Double operator
+    let error = parse_expression("3 + * 4").unwrap_err();
+    println!("{}", error);
+    assert_eq!((error.position, error.found), (4, Some('*')));
+    assert_eq!(error.expected, "a number");
+
+    // Unmatched parentheses, in both directions
+    let error = parse_expression("(1 + 2").unwrap_err();
+    println!("{}", error);
+    assert_eq!(
+        error.to_string(),
+        "Error at position 6: expected ')', found end of input"
+    );
+
+    let error = parse_expression("1 + 2)").unwrap_err();
+    println!("{}", error);
+    assert_eq!((error.position, error.found), (5, Some(')')));
+
+    // Two numbers with nothing between them
+    assert_eq!(parse_expression("1 2").unwrap_err().position, 2);
+
+    println!("All parser checks passed!");
+}
@@ -0,0 +1,221 @@
+// Phantom Types: Track the state of a value in its type, so the compiler
+// rejects code that uses it in the wrong state. A ProofData<Unverified>
+// and a ProofData<Verified> hold exactly the same bytes at runtime, but only
+// one of them can be submitted. This turns "did anyone check this proof?"
+// from a runtime bug into a compile error, and the check costs nothing:
+// PhantomData takes up no space.
+
+// Helper types: a tiny field and a one-gate circuit proving a * b = c.
+// They stand in for the real Halo2 types so the exercise runs on its own.
+const MODULUS: u64 = 97;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FieldElement(u64);
+
+impl FieldElement {
+    fn new(value: u64) -> Self {
+        FieldElement(value % MODULUS)
+    }
+
+    fn mul(self, other: FieldElement) -> FieldElement {
+        FieldElement::new(self.0 * other.0)
+    }
+}
+
+// The private witness is (a, b); the public output is a * b.
+struct ArithmeticCircuit {
+    a: FieldElement,
+    b: FieldElement,
+}
+
+impl ArithmeticCircuit {
+    fn output(&self) -> FieldElement {
+        self.a.mul(self.b)
+    }
+}
+
+// Exercise 1 Solution: State markers
+// Unit structs that are never constructed; they only exist as type
+// parameters. ProofData's fields are private to this module, so code
+// outside it can't build a ProofData<Verified> by hand.
+mod proof {
+    use super::{ArithmeticCircuit, FieldElement};
+    use std::marker::PhantomData;
+
+    pub struct Unverified;
+    pub struct Verified;
+
+    pub struct ProofData<State> {
+        data: Vec<u8>,
+        _state: PhantomData<State>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum VerifyError {
+        MalformedProof,
+        WrongPublicInputs,
+    }
+
+    // Exercise 2 Solution: Creating, verifying and submitting proofs
+    // A freshly created proof is always Unverified.
+    pub fn create_proof(circuit: &ArithmeticCircuit) -> ProofData<Unverified> {
+        // A mock proof: the public output as a single byte
+        ProofData::from_bytes(vec![circuit.output().0 as u8])
+    }
+
+    impl ProofData<Unverified> {
+        // Bytes received from somewhere else, e.g. over the network. They
+        // can be anything, so they start out Unverified like any new proof.
+        pub fn from_bytes(data: Vec<u8>) -> Self {
+            ProofData {
+                data,
+                _state: PhantomData,
+            }
+        }
+    }
+
+    impl<State> ProofData<State> {
+        pub fn len(&self) -> usize {
+            self.data.len()
+        }
+    }
+
+    // Outside this module, the only way to get a ProofData<Verified> is
+    // through this function. It consumes the unverified proof, so the
+    // unchecked copy can't be reused.
+    pub fn verify(
+        proof: ProofData<Unverified>,
+        public_inputs: &[FieldElement],
+    ) -> Result<ProofData<Verified>, VerifyError> {
+        let [claimed] = proof.data[..] else {
+            return Err(VerifyError::MalformedProof);
+        };
+        if public_inputs != [FieldElement::new(claimed as u64)] {
+            return Err(VerifyError::WrongPublicInputs);
+        }
+        Ok(ProofData {
+            data: proof.data,
+            _state: PhantomData,
+        })
+    }
+}
+
+use proof::{create_proof, verify, ProofData, Verified, VerifyError};
+
+fn submit_verified_proof(proof: ProofData<Verified>) -> String {
+    format!("Submitted proof with {} byte(s)", proof.len())
+}
+
+// Exercise 3 Solution: The same pattern for students
+// Some operations only make sense once there is at least one grade. With
+// phantom states, `average` doesn't need to return an Option at all. The
+// fields are private, so a Student<HasGrades> always has a grade.
+mod student {
+    use std::marker::PhantomData;
+
+    pub struct NoGrades;
+    pub struct HasGrades;
+
+    pub struct Student<State> {
+        name: String,
+        grades: Vec<f64>,
+        _state: PhantomData<State>,
+    }
+
+    impl Student<NoGrades> {
+        pub fn new(name: &str) -> Self {
+            Student {
+                name: name.to_string(),
+                grades: Vec::new(),
+                _state: PhantomData,
+            }
+        }
+    }
+
+    // Adding a grade works in any state and always leads to HasGrades
+    impl<State> Student<State> {
+        pub fn add_grade(mut self, grade: f64) -> Student<HasGrades> {
+            self.grades.push(grade);
+            Student {
+                name: self.name,
+                grades: self.grades,
+                _state: PhantomData,
+            }
+        }
+
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    impl Student<HasGrades> {
+        pub fn average(&self) -> f64 {
+            self.grades.iter().sum::<f64>() / self.grades.len() as f64
+        }
+    }
+}
+
+use student::Student;
+
+fn main() {
+    // Test Exercise 2: the verified path works end to end
+    let circuit = ArithmeticCircuit {
+        a: FieldElement::new(6),
+        b: FieldElement::new(7),
+    };
+    let proof = create_proof(&circuit);
+    let verified = verify(proof, &[FieldElement::new(42)]).unwrap();
+    let receipt = submit_verified_proof(verified);
+    println!("{}", receipt);
+    assert_eq!(receipt, "Submitted proof with 1 byte(s)");
+
+    // Wrong public inputs are rejected
+    let proof = create_proof(&circuit);
+    assert_eq!(
+        verify(proof, &[FieldElement::new(41)]).err(),
+        Some(VerifyError::WrongPublicInputs)
+    );
+
+    // So is a proof that doesn't have the expected shape
+    let garbage = ProofData::from_bytes(vec![1, 2, 3]);
+    assert_eq!(
+        verify(garbage, &[FieldElement::new(42)]).err(),
+        Some(VerifyError::MalformedProof)
+    );
+
+    // Uncommenting these lines is a compile error:
+    //
+    //   let unchecked = create_proof(&circuit);
+    //   submit_verified_proof(unchecked);
+    //   // error[E0308]: mismatched types
+    //   // expected `ProofData<Verified>`, found `ProofData<Unverified>`
+    //
+    // Verification also moves the proof, so it can't be checked twice:
+    //
+    //   let proof = create_proof(&circuit);
+    //   let _ = verify(proof, &[FieldElement::new(42)]);
+    //   let _ = verify(proof, &[FieldElement::new(42)]);
+    //   // error[E0382]: use of moved value: `proof`
+    //
+    // And a verified proof can't be built by hand, because the fields are
+    // private to the `proof` module:
+    //
+    //   let forged: ProofData<Verified> = ProofData {
+    //       data: vec![],
+    //       _state: std::marker::PhantomData,
+    //   };
+    //   // error[E0451]: fields `data` and `_state` of struct `ProofData` are private
+
+    // Test Exercise 3
+    let student = Student::new("Alice");
+    // student.average(); // compile error: no method `average` on Student<NoGrades>
+    let student = student.add_grade(85.0).add_grade(92.0);
+    println!("{}: {}", student.name(), student.average());
+    assert_eq!(student.average(), 88.5);
+
+    // Phantom data is free at runtime
+    assert_eq!(
+        std::mem::size_of::<ProofData<Verified>>(),
+        std::mem::size_of::<Vec<u8>>()
+    );
+}
@@ -0,0 +1,218 @@
+// Timing Exercises: Measure how long a piece of code takes, fail loudly
+// when it is too slow, and estimate how its running time grows with the
+// input size. Correct but accidentally quadratic code is a common bug in
+// proving systems, where inputs easily reach millions of rows.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+// Exercise 1 Solution: Timing a closure
+// The closure's result is passed back so the work can't be thrown away.
+fn run_timed<F: FnOnce() -> R, R>(f: F, label: &str) -> (R, Duration) {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    println!("  {}: {:?}", label, elapsed);
+    (result, elapsed)
+}
+
+// Exercise 2 Solution: A time limit
+#[derive(Debug)]
+struct TimedReport {
+    label: String,
+    actual: Duration,
+    limit: Duration,
+    passed: bool,
+}
+
+impl TimedReport {
+    fn new(label: &str, actual: Duration, limit: Duration) -> Self {
+        TimedReport {
+            label: label.to_string(),
+            actual,
+            limit,
+            passed: actual <= limit,
+        }
+    }
+}
+
+impl fmt::Display for TimedReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = if self.passed { "PASS" } else { "FAIL" };
+        write!(
+            f,
+            "[{}] {} took {:?} (limit {:?})",
+            status, self.label, self.actual, self.limit
+        )
+    }
+}
+
+fn assert_completes_within<F: FnOnce() -> R, R>(f: F, limit: Duration, label: &str) -> R {
+    let (result, actual) = run_timed(f, label);
+    let report = TimedReport::new(label, actual, limit);
+    println!("  {}", report);
+    assert!(
+        report.passed,
+        "{} exceeded its time limit: took {:?}, allowed {:?}",
+        report.label, report.actual, report.limit
+    );
+    result
+}
+
+// Exercise 3 Solution: Estimating complexity
+// For each candidate growth curve g(n), find the constant c that makes
+// c * g(n) closest to the measured times, and keep the curve with the
+// smallest error. The error is measured relative to each time, so the
+// largest input doesn't decide everything on its own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ComplexityEstimate {
+    Linear,
+    Linearithmic,
+    Quadratic,
+}
+
+impl ComplexityEstimate {
+    fn growth(self, n: f64) -> f64 {
+        match self {
+            ComplexityEstimate::Linear => n,
+            ComplexityEstimate::Linearithmic => n * n.log2(),
+            ComplexityEstimate::Quadratic => n * n,
+        }
+    }
+}
+
+impl fmt::Display for ComplexityEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ComplexityEstimate::Linear => "O(n)",
+            ComplexityEstimate::Linearithmic => "O(n log n)",
+            ComplexityEstimate::Quadratic => "O(n²)",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+fn fit_error(class: ComplexityEstimate, sizes: &[usize], times: &[Duration]) -> f64 {
+    // Minimizing sum((c * g / t - 1)^2) gives c = sum(g / t) / sum((g / t)^2)
+    let ratios: Vec<f64> = sizes
+        .iter()
+        .zip(times)
+        .map(|(&n, t)| class.growth(n as f64) / t.as_secs_f64())
+        .collect();
+    let c = ratios.iter().sum::<f64>() / ratios.iter().map(|r| r * r).sum::<f64>();
+    ratios.iter().map(|r| (c * r - 1.0).powi(2)).sum()
+}
+
+fn complexity_class(sizes: &[usize], times: &[Duration]) -> ComplexityEstimate {
+    assert_eq!(sizes.len(), times.len(), "need one time per input size");
+    assert!(
+        sizes.len() >= 2,
+        "need at least two measurements to fit a curve"
+    );
+
+    [
+        ComplexityEstimate::Linear,
+        ComplexityEstimate::Linearithmic,
+        ComplexityEstimate::Quadratic,
+    ]
+    .into_iter()
+    .map(|class| (class, fit_error(class, sizes, times)))
+    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    .map(|(class, _)| class)
+    .unwrap()
+}
+
+// Helper function: deterministic pseudo-random input (xorshift)
+fn pseudo_random(len: usize, mut seed: u64) -> Vec<u64> {
+    (0..len)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        })
+        .collect()
+}
+
+// Time sorts of random values of each size. Every round measures every
+// size, and each size keeps its fastest round, so a slowdown elsewhere on
+// the machine hits all sizes alike instead of bending the curve.
+fn time_sorts(sizes: &[usize]) -> Vec<Duration> {
+    let mut best = vec![Duration::MAX; sizes.len()];
+    for round in 0..7 {
+        for (best, &n) in best.iter_mut().zip(sizes) {
+            let mut data = pseudo_random(n, 0x9E37_79B9_7F4A_7C15 + round);
+            let start = Instant::now();
+            data.sort_unstable();
+            *best = (*best).min(start.elapsed());
+            assert!(data.windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
+    best
+}
+
+fn main() {
+    // Test Exercise 1
+    println!("=== Exercise 1: run_timed ===");
+    let (sum, elapsed) = run_timed(|| (1..=1_000u64).sum::<u64>(), "sum of 1..=1000");
+    assert_eq!(sum, 500_500);
+    assert!(elapsed < Duration::from_secs(1));
+
+    // Test Exercise 2: a fast closure passes and hands back its result
+    println!("\n=== Exercise 2: assert_completes_within ===");
+    let sorted = assert_completes_within(
+        || {
+            let mut data = pseudo_random(10_000, 1);
+            data.sort();
+            data
+        },
+        Duration::from_secs(5),
+        "sorting 10,000 values",
+    );
+    assert_eq!(sorted.len(), 10_000);
+
+    // A slow closure panics with a descriptive message. The default hook
+    // is swapped out while it runs so the expected panic isn't printed.
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let outcome = std::panic::catch_unwind(|| {
+        assert_completes_within(
+            || std::thread::sleep(Duration::from_millis(20)),
+            Duration::from_millis(1),
+            "sleeping 20ms",
+        )
+    });
+    std::panic::set_hook(default_hook);
+    let message = outcome.unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("sleeping 20ms exceeded its time limit"));
+
+    let report = TimedReport::new("demo", Duration::from_millis(3), Duration::from_millis(2));
+    assert!(!report.passed);
+    assert_eq!(report.to_string(), "[FAIL] demo took 3ms (limit 2ms)");
+
+    // Test Exercise 3: exact curves are recognized
+    println!("\n=== Exercise 3: complexity_class ===");
+    let sizes = [1_000, 2_000, 4_000, 8_000, 16_000];
+    for class in [
+        ComplexityEstimate::Linear,
+        ComplexityEstimate::Linearithmic,
+        ComplexityEstimate::Quadratic,
+    ] {
+        let times: Vec<Duration> = sizes
+            .iter()
+            .map(|&n| Duration::from_nanos(3 * class.growth(n as f64) as u64))
+            .collect();
+        assert_eq!(complexity_class(&sizes, &times), class);
+    }
+
+    // Real measurements: sorting is O(n log n)
+    let sizes: Vec<usize> = (10..=20).map(|k| 1 << k).collect();
+    let times = time_sorts(&sizes);
+    for (n, t) in sizes.iter().zip(&times) {
+        println!("  sort of {:>7} values: {:?}", n, t);
+    }
+    let estimate = complexity_class(&sizes, &times);
+    println!("  Estimated complexity: {}", estimate);
+    assert_eq!(estimate, ComplexityEstimate::Linearithmic);
+}
@@ -0,0 +1,432 @@
+// Anti-Pattern Checkers: Small tools that read Rust source as plain text
+// and point out habits worth improving. They use simple string matching,
+// not a real parser, so they are teaching aids rather than a replacement
+// for clippy: they can be fooled, but they should never complain about
+// code that is fine.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+// Types that implement Copy, so calling clone() on them is redundant
+const COPY_TYPES: [&str; 16] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    "f64", "bool", "char",
+];
+
+// Helper function: the code without its comments and literals. A `//`
+// comment is dropped up to the end of its line, and the contents of string
+// and char literals are blanked out with spaces. What's left keeps the
+// same lines, so line numbers still match, and a `}` or `.unwrap()` inside
+// a string can't fool the checkers.
+fn code_only(source: &str) -> String {
+    let mut code = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => while chars.next_if(|&c| c != '\n').is_some() {},
+            '"' => {
+                code.push('"');
+                blank_literal(&mut chars, &mut code, '"');
+            }
+            // 'x' and '\n' are char literals, but 'a in <'a> is a lifetime
+            '\'' => {
+                let mut ahead = chars.clone();
+                let is_char = match ahead.next() {
+                    Some('\\') => true,
+                    Some(_) => ahead.next() == Some('\''),
+                    None => false,
+                };
+                code.push('\'');
+                if is_char {
+                    blank_literal(&mut chars, &mut code, '\'');
+                }
+            }
+            _ => code.push(c),
+        }
+    }
+    code
+}
+
+// Replace everything up to the closing quote with spaces, keeping
+// newlines and skipping over escaped characters
+fn blank_literal(chars: &mut Peekable<Chars>, code: &mut String, quote: char) {
+    while let Some(c) = chars.next() {
+        if c == quote {
+            code.push(quote);
+            return;
+        }
+        let c = if c == '\\' {
+            code.push(' ');
+            chars.next().unwrap_or(' ')
+        } else {
+            c
+        };
+        code.push(if c == '\n' { '\n' } else { ' ' });
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Helper function: the name of the fn or mod declared on a line, if any
+fn declared_name<'a>(line: &'a str, keyword: &str) -> Option<&'a str> {
+    let start = line
+        .match_indices(keyword)
+        .find(|&(index, _)| !line[..index].ends_with(is_ident_char))?
+        .0
+        + keyword.len();
+    let rest = &line[start..];
+    let end = rest.find(|c: char| !is_ident_char(c)).unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+// Helper type: the fn and mod blocks open at the current point in the
+// code, each with some data a checker wants to keep about it. The code
+// is fed in one character at a time; only braces and semicolons matter.
+struct Scope<T> {
+    name: String,
+    depth: usize,
+    data: T,
+}
+
+struct BlockTracker<T> {
+    scopes: Vec<Scope<T>>,
+    depth: usize,
+    // A fn or mod whose opening brace hasn't been reached yet
+    pending: Option<(String, T)>,
+}
+
+impl<T> BlockTracker<T> {
+    fn new() -> Self {
+        BlockTracker {
+            scopes: Vec::new(),
+            depth: 0,
+            pending: None,
+        }
+    }
+
+    fn declare(&mut self, name: &str, data: T) {
+        self.pending = Some((name.to_string(), data));
+    }
+
+    fn step(&mut self, c: char) {
+        match c {
+            '{' => {
+                if let Some((name, data)) = self.pending.take() {
+                    self.scopes.push(Scope {
+                        name,
+                        depth: self.depth,
+                        data,
+                    });
+                }
+                self.depth += 1;
+            }
+            '}' => {
+                // An unmatched `}` in broken code must not underflow
+                self.depth = self.depth.saturating_sub(1);
+                if self.scopes.last().is_some_and(|s| s.depth == self.depth) {
+                    self.scopes.pop();
+                }
+            }
+            // A declaration without a body, like a trait method
+            ';' => self.pending = None,
+            _ => {}
+        }
+    }
+}
+
+// Exercise 1 Solution: clone() on Copy types
+// Record every `name: Type` declaration, like `x: u64` or `count: &usize`,
+// in the fn it belongs to (a fn's parameters count as inside it). For a
+// `name.clone()` the innermost declaration of that name decides, so `x`
+// can be a u64 in one function and a String in the next.
+type Declarations = Vec<(String, String)>;
+
+// The `name` and `Type` around the colon at `colon`, skipping paths like
+// std::mem
+fn declaration_at(line: &str, colon: usize) -> Option<(String, String)> {
+    let before = &line[..colon];
+    let after = &line[colon + 1..];
+    if before.ends_with(':') || after.starts_with(':') {
+        return None;
+    }
+    let before = before.trim_end();
+    let name = &before[before.trim_end_matches(is_ident_char).len()..];
+    let ty: String = after
+        .trim_start()
+        .trim_start_matches('&')
+        .trim_start_matches("mut ")
+        .chars()
+        .take_while(|&c| is_ident_char(c))
+        .collect();
+    (!name.is_empty()).then(|| (name.to_string(), ty))
+}
+
+fn check_uses_clone_unnecessarily(source: &str) -> Vec<String> {
+    let mut tracker: BlockTracker<Declarations> = BlockTracker::new();
+    // Struct fields, consts and anything else outside every fn
+    let mut top_level: Declarations = Vec::new();
+    let mut warnings = Vec::new();
+
+    for (number, line) in code_only(source).lines().enumerate() {
+        if let Some(name) = declared_name(line, "fn ").or_else(|| declared_name(line, "mod ")) {
+            tracker.declare(name, Vec::new());
+        }
+
+        for (index, c) in line.char_indices() {
+            tracker.step(c);
+            if c == ':' {
+                if let Some(declaration) = declaration_at(line, index) {
+                    let frame = match (&mut tracker.pending, tracker.scopes.last_mut()) {
+                        (Some((_, params)), _) => params,
+                        (None, Some(scope)) => &mut scope.data,
+                        (None, None) => &mut top_level,
+                    };
+                    frame.push(declaration);
+                }
+            } else if c == '.' && line[index..].starts_with(".clone()") {
+                let before = &line[..index];
+                let name = &before[before.trim_end_matches(is_ident_char).len()..];
+                // Later declarations shadow earlier ones, inner fns outer ones
+                let ty = tracker
+                    .scopes
+                    .iter()
+                    .rev()
+                    .map(|scope| &scope.data)
+                    .chain([&top_level])
+                    .find_map(|frame| frame.iter().rev().find(|(n, _)| n == name))
+                    .map(|(_, ty)| ty.as_str());
+                if let Some(ty) = ty.filter(|ty| COPY_TYPES.contains(ty)) {
+                    warnings.push(format!(
+                        "line {}: `{}.clone()` copies a `{}`, which is Copy; use `{}` directly",
+                        number + 1,
+                        name,
+                        ty,
+                        name
+                    ));
+                }
+            }
+        }
+    }
+    warnings
+}
+
+// Exercise 2 Solution: Collecting a Vec only to iterate over it
+// Flags `.collect::<Vec<_>>()` when the very next thing is `.iter()`,
+// `.into_iter()` or `.iter_mut()`, or when it is the end of a `for` loop
+// header. In both cases the Vec is an extra allocation the iterator chain
+// didn't need.
+fn check_collects_to_vec_unnecessarily(source: &str) -> Vec<String> {
+    const COLLECT: &str = ".collect::<Vec<_>>()";
+    let code = code_only(source);
+
+    let mut warnings = Vec::new();
+    for (index, _) in code.match_indices(COLLECT) {
+        let rest = code[index + COLLECT.len()..].trim_start();
+        let line_start = code[..index].rfind('\n').map_or(0, |i| i + 1);
+        let in_for_header = code[line_start..index].trim_start().starts_with("for ");
+
+        let iterated = [".iter()", ".into_iter()", ".iter_mut()"]
+            .iter()
+            .any(|call| rest.starts_with(call));
+        if iterated || (in_for_header && rest.starts_with('{')) {
+            let line = code[..index].matches('\n').count() + 1;
+            warnings.push(format!(
+                "line {}: collecting into a Vec only to iterate over it; keep the iterator chain going",
+                line
+            ));
+        }
+    }
+    warnings
+}
+
+// Exercise 3 Solution: unwrap() outside main and tests
+// Walk the source brace by brace, keeping a stack of the fn and mod
+// blocks we are inside. A block is allowed to unwrap if it is `main`, is
+// marked #[test] or #[cfg(test)], or sits inside a block that is.
+fn check_unwrap_in_library_code(source: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let mut tracker: BlockTracker<bool> = BlockTracker::new();
+    let mut test_attribute = false;
+
+    for (number, line) in code_only(source).lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed == "#[test]" || trimmed == "#[cfg(test)]" {
+            test_attribute = true;
+            continue;
+        }
+
+        if let Some(name) = declared_name(line, "fn ").or_else(|| declared_name(line, "mod ")) {
+            let inherited = tracker.scopes.iter().any(|scope| scope.data);
+            tracker.declare(name, inherited || test_attribute || name == "main");
+            test_attribute = false;
+        }
+
+        for (index, c) in line.char_indices() {
+            tracker.step(c);
+            if c == '.'
+                && line[index..].starts_with(".unwrap()")
+                && !tracker.scopes.iter().any(|scope| scope.data)
+            {
+                let function = tracker
+                    .scopes
+                    .last()
+                    .map_or("top level", |s| s.name.as_str());
+                warnings.push(format!(
+                    "line {}: `.unwrap()` in `{}`; return a Result or use `expect` with a reason",
+                    number + 1,
+                    function
+                ));
+            }
+        }
+    }
+    warnings
+}
+
+fn main() {
+    // Test Exercise 1
+    let cloning = r#"
+fn total(a: u64, b: &u64, name: String) -> u64 {
+    let label = name.clone();
+    let flag: bool = true;
+    let copied = flag.clone();
+    a.clone() + b.clone()
+}
+"#;
+    let warnings = check_uses_clone_unnecessarily(cloning);
+    for warning in &warnings {
+        println!("{}", warning);
+    }
+    assert_eq!(
+        warnings,
+        vec![
+            "line 5: `flag.clone()` copies a `bool`, which is Copy; use `flag` directly",
+            "line 6: `a.clone()` copies a `u64`, which is Copy; use `a` directly",
+            "line 6: `b.clone()` copies a `u64`, which is Copy; use `b` directly",
+        ]
+    );
+
+    // Test Exercise 2
+    let collecting = r#"
+fn squares(values: &[u64]) -> u64 {
+    let doubled = values.iter().map(|v| v * 2).collect::<Vec<_>>().iter().sum();
+    for v in values.iter().filter(|v| **v > 3).collect::<Vec<_>>() {
+        println!("{}", v);
+    }
+    values
+        .iter()
+        .map(|v| v * v)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .sum()
+}
+"#;
+    let warnings = check_collects_to_vec_unnecessarily(collecting);
+    for warning in &warnings {
+        println!("{}", warning);
+    }
+    let lines: Vec<&str> = warnings
+        .iter()
+        .filter_map(|w| w.split(':').next())
+        .collect();
+    assert_eq!(lines, vec!["line 3", "line 4", "line 10"]);
+
+    // Test Exercise 3
+    let unwrapping = r#"
+fn parse_grade(input: &str) -> f64 {
+    input.trim().parse().unwrap()
+}
+
+fn main() {
+    let grade = parse_grade("95").max(0.0);
+    let first = "1 2".split(' ').next().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() -> u64 {
+        "7".parse().unwrap()
+    }
+
+    #[test]
+    fn parses() {
+        assert_eq!(super::parse_grade("80"), 80.0);
+        let _ = Some(1).unwrap();
+    }
+}
+"#;
+    let warnings = check_unwrap_in_library_code(unwrapping);
+    for warning in &warnings {
+        println!("{}", warning);
+    }
+    assert_eq!(
+        warnings,
+        vec!["line 3: `.unwrap()` in `parse_grade`; return a Result or use `expect` with a reason"]
+    );
+
+    // Braces inside string and char literals don't open or close blocks
+    let literals = "const CLOSE: &str = \"}\";\nfn f() -> u8 { Some(1).unwrap() }";
+    assert_eq!(
+        check_unwrap_in_library_code(literals),
+        vec!["line 2: `.unwrap()` in `f`; return a Result or use `expect` with a reason"]
+    );
+    let literals = "fn main() {\n    let (open, close) = ('{', '}');\n    let s = \"{\\\"}\";\n    x.unwrap();\n}";
+    assert!(check_unwrap_in_library_code(literals).is_empty());
+    // A stray closing brace is survived, not a panic
+    assert_eq!(
+        check_unwrap_in_library_code("}\n}\nfn f() { g().unwrap() }").len(),
+        1
+    );
+
+    // Well-written code: no warnings from any checker
+    let clean = r#"
+use std::collections::HashMap;
+use std::num::ParseFloatError;
+
+trait Grader {
+    fn grade(&self, score: f64) -> char;
+}
+
+fn parse_grades(input: &str) -> Result<Vec<f64>, ParseFloatError> {
+    input.split(',').map(|s| s.trim().parse::<f64>()).collect()
+}
+
+fn summarize(name: &String, scores: &[f64], count: usize) -> (String, usize) {
+    // scores.clone() and .unwrap() in a comment are fine
+    let owned: Vec<f64> = scores.to_vec();
+    let names = scores.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let mut by_name: HashMap<String, usize> = HashMap::new();
+    by_name.insert(name.clone(), names.len() + owned.len());
+    let total: f64 = scores.iter().sum();
+    let first = scores.first().copied().unwrap_or(total);
+    (name.clone(), count + first as usize)
+}
+
+fn main() {
+    let grades = parse_grades("90, 85").unwrap();
+    println!("{:?}", summarize(&"Alice".to_string(), &grades, 2));
+}
+"#;
+    assert!(check_uses_clone_unnecessarily(clean).is_empty());
+    assert!(check_collects_to_vec_unnecessarily(clean).is_empty());
+    assert!(check_unwrap_in_library_code(clean).is_empty());
+
+    // The same name can have a different type in each function
+    let reused = "fn f(x: u64) -> u64 { x }\nfn g(x: String) -> String { x.clone() }";
+    assert!(check_uses_clone_unnecessarily(reused).is_empty());
+    let reused = r#"
+fn f(x: String) -> String { x.clone() }
+fn g(x: u64) -> u64 {
+    let label: String = format!("{}", x);
+    label.clone();
+    x.clone()
+}
+"#;
+    assert_eq!(
+        check_uses_clone_unnecessarily(reused),
+        vec!["line 6: `x.clone()` copies a `u64`, which is Copy; use `x` directly"]
+    );
+
+    println!("All anti-pattern checks passed!");
+}
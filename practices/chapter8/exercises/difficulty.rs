@@ -0,0 +1,255 @@
+// Exercise Difficulty: Guess how hard a piece of Rust is to read from the
+// features it uses. Each feature below adds to a score, the score becomes
+// a level from 1 to 10, and every point comes with a reason, so the
+// estimate can be checked by hand. Like the anti-pattern checkers, this
+// works on plain text with a few string searches.
+
+use std::collections::BTreeSet;
+use std::iter::Peekable;
+use std::str::Chars;
+
+// Helper function: the code without its comments and literals, so a word
+// like "unsafe" in a comment or a string doesn't count. Lines are kept.
+fn code_only(source: &str) -> String {
+    let mut code = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => while chars.next_if(|&c| c != '\n').is_some() {},
+            '"' => {
+                code.push('"');
+                blank_literal(&mut chars, &mut code, '"');
+            }
+            // 'x' and '\n' are char literals, but 'a in <'a> is a lifetime
+            '\'' => {
+                let mut ahead = chars.clone();
+                let is_char = match ahead.next() {
+                    Some('\\') => true,
+                    Some(_) => ahead.next() == Some('\''),
+                    None => false,
+                };
+                code.push('\'');
+                if is_char {
+                    blank_literal(&mut chars, &mut code, '\'');
+                }
+            }
+            _ => code.push(c),
+        }
+    }
+    code
+}
+
+fn blank_literal(chars: &mut Peekable<Chars>, code: &mut String, quote: char) {
+    while let Some(c) = chars.next() {
+        if c == quote {
+            code.push(quote);
+            return;
+        }
+        let c = if c == '\\' {
+            code.push(' ');
+            chars.next().unwrap_or(' ')
+        } else {
+            c
+        };
+        code.push(if c == '\n' { '\n' } else { ' ' });
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Exercise 1 Solution: Spotting the features
+// Each `unsafe` keyword that opens a block
+fn count_unsafe_blocks(code: &str) -> usize {
+    code.match_indices("unsafe")
+        .filter(|&(index, word)| {
+            !code[..index].ends_with(is_ident_char)
+                && code[index + word.len()..].trim_start().starts_with('{')
+        })
+        .count()
+}
+
+// The distinct lifetime names, like 'a and 'static. A name followed by a
+// colon is a loop label ('outer: loop) and is left out.
+fn lifetimes(code: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    for (index, _) in code.match_indices('\'') {
+        let rest = &code[index + 1..];
+        let name: String = rest.chars().take_while(|&c| is_ident_char(c)).collect();
+        let starts_like_a_name = name.starts_with(|c: char| c.is_alphabetic() || c == '_');
+        if starts_like_a_name && !rest[name.len()..].starts_with(':') {
+            names.insert(format!("'{}", name));
+        }
+    }
+    names
+}
+
+// Functions whose body calls the function itself. The body is everything
+// from the first `{` after `fn name` to its matching `}`; a `;` first means
+// there is no body, as in a trait. A call through a path, like
+// `VecDeque::new()` inside `fn new`, is some other type's function.
+fn recursive_functions(code: &str) -> Vec<String> {
+    let mut found = Vec::new();
+    for (index, _) in code.match_indices("fn ") {
+        if code[..index].ends_with(is_ident_char) {
+            continue;
+        }
+        let rest = &code[index + 3..];
+        let name: String = rest
+            .trim_start()
+            .chars()
+            .take_while(|&c| is_ident_char(c))
+            .collect();
+        let Some(open) = rest.find(['{', ';']) else {
+            continue;
+        };
+        if name.is_empty() || rest[open..].starts_with(';') {
+            continue;
+        }
+
+        let mut depth = 0;
+        let mut end = rest.len();
+        for (i, c) in rest[open..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = open + i;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let body = &rest[open..end];
+        let call = format!("{}(", name);
+        let calls_itself = body
+            .match_indices(&call)
+            .any(|(i, _)| !body[..i].ends_with(|c| is_ident_char(c) || c == ':'));
+        if calls_itself {
+            found.push(name);
+        }
+    }
+    found
+}
+
+// Exercise 2 Solution: The estimate
+#[derive(Debug)]
+struct DifficultyEstimate {
+    level: u8,
+    reasons: Vec<String>,
+}
+
+// Every file starts at level 1. Unsafe blocks add 2 each and lifetimes 1
+// per distinct name; the other features count once however often they
+// appear: Rc<RefCell<...>> +1, Box<dyn ...> +1, async fn +2, recursion +1.
+fn estimate_exercise_difficulty(source: &str) -> DifficultyEstimate {
+    let code = code_only(source);
+    let mut score = 0;
+    let mut reasons = Vec::new();
+    let mut add = |points: usize, reason: String| {
+        score += points;
+        reasons.push(format!("{} (+{})", reason, points));
+    };
+
+    let unsafe_blocks = count_unsafe_blocks(&code);
+    if unsafe_blocks > 0 {
+        add(
+            2 * unsafe_blocks,
+            format!("{} unsafe block(s)", unsafe_blocks),
+        );
+    }
+    if code.contains("Rc<RefCell<") {
+        add(1, "shared mutable state with Rc<RefCell<...>>".to_string());
+    }
+    if code.contains("Box<dyn ") {
+        add(1, "trait objects with Box<dyn ...>".to_string());
+    }
+    let lifetimes = lifetimes(&code);
+    if !lifetimes.is_empty() {
+        let names: Vec<&str> = lifetimes.iter().map(String::as_str).collect();
+        add(lifetimes.len(), format!("lifetimes {}", names.join(", ")));
+    }
+    if code.contains("async fn ") {
+        add(2, "async functions".to_string());
+    }
+    let recursive = recursive_functions(&code);
+    if !recursive.is_empty() {
+        add(1, format!("recursion in {}", recursive.join(", ")));
+    }
+
+    DifficultyEstimate {
+        level: (1 + score).clamp(1, 10) as u8,
+        reasons,
+    }
+}
+
+// Exercise 3 Solution: What to practise first
+// The exercise in this repository that covers each feature
+fn suggest_prerequisites(source: &str) -> Vec<&'static str> {
+    let code = code_only(source);
+    let mut suggestions = Vec::new();
+    if !recursive_functions(&code).is_empty() {
+        suggestions.push("chapter3/recursion");
+    }
+    if code.contains("Box<dyn ") {
+        suggestions.push("chapter5/state_machine");
+    }
+    if !lifetimes(&code).is_empty() {
+        suggestions.push("chapter6/strict_parser");
+    }
+    if code.contains("Rc<RefCell<") {
+        suggestions.push("chapter9/parallel_merkle");
+    }
+    if code.contains("async fn ") {
+        suggestions.push("chapter10/async_prover");
+    }
+    if count_unsafe_blocks(&code) > 0 {
+        suggestions.push("chapter11/ffi");
+    }
+    suggestions
+}
+
+fn main() {
+    // Test Exercise 2: basic control flow is easier than an async executor
+    let control_flow = include_str!("../../chapter2/exercises/control_flow.rs");
+    let async_prover = include_str!("../../chapter10/exercises/async_prover.rs");
+    let easy = estimate_exercise_difficulty(control_flow);
+    let hard = estimate_exercise_difficulty(async_prover);
+    println!("control_flow.rs: level {} {:?}", easy.level, easy.reasons);
+    println!("async_prover.rs: level {} {:?}", hard.level, hard.reasons);
+    assert!(easy.level < hard.level);
+
+    // The same function with and without an unsafe block
+    let safe = "fn first(v: &[u8]) -> u8 {\n    v[0]\n}";
+    let with_unsafe = "fn first(v: &[u8]) -> u8 {\n    unsafe { *v.get_unchecked(0) }\n}";
+    let safe = estimate_exercise_difficulty(safe);
+    let with_unsafe = estimate_exercise_difficulty(with_unsafe);
+    assert_eq!(safe.level, 1);
+    assert!(safe.reasons.is_empty());
+    assert_eq!(with_unsafe.level, 3);
+    assert!(with_unsafe.level > safe.level);
+
+    // Comments and strings don't count, and the level stops at 10
+    let mentions = "// unsafe { Rc<RefCell<T>> }\nfn f() -> &'static str { \"async fn x() {}\" }";
+    assert_eq!(estimate_exercise_difficulty(mentions).level, 2); // 'static
+    let scary = "fn f() { unsafe {} unsafe {} unsafe {} unsafe {} unsafe {} unsafe {} }";
+    assert_eq!(estimate_exercise_difficulty(scary).level, 10);
+
+    // Test Exercise 1: the individual features
+    let code = "fn longest<'a, 'b>(x: &'a str, _: &'b str) -> &'a str { x }\nfn f(c: char) { 'outer: loop { let _ = ('x', c); break 'outer; } }";
+    let names: Vec<String> = lifetimes(&code_only(code)).into_iter().collect();
+    assert_eq!(names, vec!["'a", "'b", "'outer"]);
+    let code = "fn fib(n: u64) -> u64 {\n    if n < 2 { n } else { fib(n - 1) + fib(n - 2) }\n}\nfn not_fib(n: u64) -> u64 { n }";
+    assert_eq!(recursive_functions(code), vec!["fib"]);
+
+    // Test Exercise 3
+    let prerequisites = suggest_prerequisites(async_prover);
+    println!("Before async_prover.rs: {:?}", prerequisites);
+    assert!(prerequisites.contains(&"chapter10/async_prover"));
+    assert!(prerequisites.contains(&"chapter11/ffi"));
+    assert!(suggest_prerequisites(control_flow).is_empty());
+    assert_eq!(suggest_prerequisites(code), vec!["chapter3/recursion"]);
+}
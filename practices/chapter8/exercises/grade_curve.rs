@@ -0,0 +1,119 @@
+// Grade Curves: A curve is a function from the old grade to the new one,
+// so CurveMethod can hold any of them, even a closure the instructor
+// writes, as a Box<dyn Fn(f64) -> f64>. Applying a curve to a whole class
+// is then just a map over every grade.
+
+// Exercise 1 Solution: The curves
+enum CurveMethod {
+    // Add a fixed number of points
+    Linear(f64),
+    // 100 * sqrt(grade / 100): lifts low grades the most
+    SquareRoot,
+    // 100 * (1 - e^(-rate * grade / 100)): the larger the rate, the harder
+    // everyone is pushed towards 100
+    ExponentialDecay { rate: f64 },
+    Custom(Box<dyn Fn(f64) -> f64>),
+}
+
+// Grades go in and come out within [0, 100]. Clamping the input first
+// keeps the square root away from negative numbers.
+fn apply_curve(grade: f64, method: &CurveMethod) -> f64 {
+    let grade = grade.clamp(0.0, 100.0);
+    let curved = match method {
+        CurveMethod::Linear(points) => grade + points,
+        CurveMethod::SquareRoot => 100.0 * (grade / 100.0).sqrt(),
+        CurveMethod::ExponentialDecay { rate } => 100.0 * (1.0 - (-rate * grade / 100.0).exp()),
+        CurveMethod::Custom(f) => f(grade),
+    };
+    curved.clamp(0.0, 100.0)
+}
+
+// Exercise 2 Solution: Curving a class
+// Helper type: just enough of a student for this exercise
+#[derive(Debug, Clone, PartialEq)]
+struct Student {
+    name: String,
+    grades: Vec<f64>,
+}
+
+// Takes a slice, so a `&mut Vec<Student>` works as it is
+fn apply_curve_to_class(students: &mut [Student], method: &CurveMethod) {
+    for grade in students.iter_mut().flat_map(|s| s.grades.iter_mut()) {
+        *grade = apply_curve(*grade, method);
+    }
+}
+
+// Exercise 3 Solution: Before and after, without changing anything
+fn preview_curve(method: &CurveMethod, sample_grades: &[f64]) -> Vec<(f64, f64)> {
+    sample_grades
+        .iter()
+        .map(|&grade| (grade, apply_curve(grade, method)))
+        .collect()
+}
+
+fn main() {
+    let methods = [
+        CurveMethod::Linear(5.0),
+        CurveMethod::Linear(-10.0),
+        CurveMethod::SquareRoot,
+        CurveMethod::ExponentialDecay { rate: 2.0 },
+        CurveMethod::ExponentialDecay { rate: 0.5 },
+        CurveMethod::Custom(Box::new(|g| g * 1.1 + 3.0)),
+    ];
+
+    // Test Exercise 1: known values
+    assert_eq!(apply_curve(81.0, &CurveMethod::SquareRoot), 90.0);
+    assert_eq!(apply_curve(36.0, &CurveMethod::SquareRoot), 60.0);
+    assert_eq!(apply_curve(97.0, &CurveMethod::Linear(5.0)), 100.0);
+    let rate = 2.0;
+    let expected = 100.0 * (1.0 - (-rate * 0.5f64).exp());
+    let curved = apply_curve(50.0, &CurveMethod::ExponentialDecay { rate });
+    assert!((curved - expected).abs() < 1e-12);
+
+    // Every curve keeps grades in [0, 100] and never swaps two students'
+    // order, even for grades that started out of range
+    let samples: Vec<f64> = (-20..=120).map(|i| i as f64).collect();
+    for method in &methods {
+        let preview = preview_curve(method, &samples);
+        assert!(preview
+            .iter()
+            .all(|&(_, after)| (0.0..=100.0).contains(&after)));
+        assert!(preview.windows(2).all(|w| w[0].1 <= w[1].1));
+    }
+    // The ones that aren't clamped are strictly increasing
+    for method in &methods[2..5] {
+        let preview = preview_curve(method, &[0.0, 25.0, 50.0, 75.0, 100.0]);
+        assert!(preview.windows(2).all(|w| w[0].1 < w[1].1));
+        assert_eq!(preview[0].1, 0.0);
+    }
+
+    // Test Exercise 3: preview shows before and after
+    let preview = preview_curve(&CurveMethod::SquareRoot, &[25.0, 64.0, 100.0]);
+    println!("Square root curve: {:?}", preview);
+    assert_eq!(preview, vec![(25.0, 50.0), (64.0, 80.0), (100.0, 100.0)]);
+
+    // Test Exercise 2: the class is curved in place
+    let mut students = vec![
+        Student {
+            name: "Alice".to_string(),
+            grades: vec![49.0, 81.0],
+        },
+        Student {
+            name: "Bob".to_string(),
+            grades: vec![16.0, 100.0],
+        },
+    ];
+    let before = students.clone();
+    apply_curve_to_class(&mut students, &CurveMethod::SquareRoot);
+    for student in &students {
+        println!("{}: {:?}", student.name, student.grades);
+    }
+    assert_eq!(students[0].grades, vec![70.0, 90.0]);
+    assert_eq!(students[1].grades, vec![40.0, 100.0]);
+    // preview_curve leaves its input alone, so it matches what would happen
+    for (old, new) in before.iter().zip(&students) {
+        let preview = preview_curve(&CurveMethod::SquareRoot, &old.grades);
+        let after: Vec<f64> = preview.iter().map(|&(_, a)| a).collect();
+        assert_eq!(after, new.grades);
+    }
+}
@@ -0,0 +1,180 @@
+// Optimization: Find the input that makes a function as small as
+// possible by walking downhill. The function and its gradient are passed
+// in as closures, so the same optimizer works for anything we can write
+// as `impl Fn(&[f64]) -> f64`, such as the error of a polynomial fit.
+
+// Exercise 1 Solution: Gradient descent
+// Step against the gradient, learning_rate times its length, n_iters
+// times. `f` decides which point visited was best, so a learning rate that
+// is too large, and makes the steps overshoot, still returns the best
+// point seen instead of the last one.
+fn gradient_descent(
+    f: impl Fn(&[f64]) -> f64,
+    grad_f: impl Fn(&[f64]) -> Vec<f64>,
+    initial: &[f64],
+    learning_rate: f64,
+    n_iters: usize,
+) -> Vec<f64> {
+    let mut x = initial.to_vec();
+    let mut best = (f(&x), x.clone());
+    for _ in 0..n_iters {
+        let gradient = grad_f(&x);
+        x = x
+            .iter()
+            .zip(&gradient)
+            .map(|(xi, gi)| xi - learning_rate * gi)
+            .collect();
+        let value = f(&x);
+        if value < best.0 {
+            best = (value, x.clone());
+        }
+    }
+    best.1
+}
+
+// Exercise 2 Solution: Fitting a polynomial
+// Helper type: a polynomial as its coefficients, lowest degree first
+#[derive(Debug, Clone, PartialEq)]
+struct Polynomial<T> {
+    coefficients: Vec<T>,
+}
+
+impl Polynomial<f64> {
+    // Horner's method
+    fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, c| acc * x + c)
+    }
+}
+
+fn mean_squared_error(poly: &Polynomial<f64>, points: &[(f64, f64)]) -> f64 {
+    points
+        .iter()
+        .map(|&(x, y)| (poly.evaluate(x) - y).powi(2))
+        .sum::<f64>()
+        / points.len() as f64
+}
+
+// The coefficients are the variables being optimized. The derivative of
+// the MSE with respect to coefficient j is the average of
+// 2 * (p(x) - y) * x^j over the points.
+fn fit_polynomial_gd(
+    points: &[(f64, f64)],
+    degree: usize,
+    learning_rate: f64,
+    n_iters: usize,
+) -> Polynomial<f64> {
+    let as_poly = |c: &[f64]| Polynomial {
+        coefficients: c.to_vec(),
+    };
+    let loss = |c: &[f64]| mean_squared_error(&as_poly(c), points);
+    let gradient = |c: &[f64]| {
+        let poly = as_poly(c);
+        (0..c.len() as i32)
+            .map(|j| {
+                points
+                    .iter()
+                    .map(|&(x, y)| 2.0 * (poly.evaluate(x) - y) * x.powi(j))
+                    .sum::<f64>()
+                    / points.len() as f64
+            })
+            .collect()
+    };
+    let initial = vec![0.0; degree + 1];
+    as_poly(&gradient_descent(
+        loss,
+        gradient,
+        &initial,
+        learning_rate,
+        n_iters,
+    ))
+}
+
+// Exercise 3 Solution: Adam
+// Adam keeps running averages of the gradient (m) and of its square (v)
+// and steps by m / sqrt(v). Every coordinate then moves at about the same
+// speed however steep it is, which helps when some directions are much
+// steeper than others. The hyperparameters are the defaults from the
+// Adam paper.
+const ADAM_LEARNING_RATE: f64 = 0.001;
+const ADAM_BETA1: f64 = 0.9;
+const ADAM_BETA2: f64 = 0.999;
+const ADAM_EPSILON: f64 = 1e-8;
+const ADAM_ITERATIONS: usize = 10_000;
+
+fn adam_optimizer(
+    f: impl Fn(&[f64]) -> f64,
+    grad_f: impl Fn(&[f64]) -> Vec<f64>,
+    initial: &[f64],
+) -> Vec<f64> {
+    let mut x = initial.to_vec();
+    let mut m = vec![0.0; x.len()];
+    let mut v = vec![0.0; x.len()];
+    let mut best = (f(&x), x.clone());
+
+    for t in 1..=ADAM_ITERATIONS as i32 {
+        let gradient = grad_f(&x);
+        for i in 0..x.len() {
+            m[i] = ADAM_BETA1 * m[i] + (1.0 - ADAM_BETA1) * gradient[i];
+            v[i] = ADAM_BETA2 * v[i] + (1.0 - ADAM_BETA2) * gradient[i].powi(2);
+            // Both averages start at 0, which biases them low early on
+            let m_hat = m[i] / (1.0 - ADAM_BETA1.powi(t));
+            let v_hat = v[i] / (1.0 - ADAM_BETA2.powi(t));
+            x[i] -= ADAM_LEARNING_RATE * m_hat / (v_hat.sqrt() + ADAM_EPSILON);
+        }
+        let value = f(&x);
+        if value < best.0 {
+            best = (value, x.clone());
+        }
+    }
+    best.1
+}
+
+fn main() {
+    // A convex quadratic with its minimum at (3, -2), much steeper in the
+    // second direction
+    let f = |x: &[f64]| (x[0] - 3.0).powi(2) + 10.0 * (x[1] + 2.0).powi(2);
+    let grad_f = |x: &[f64]| vec![2.0 * (x[0] - 3.0), 20.0 * (x[1] + 2.0)];
+
+    // Test Exercise 1: gradient descent converges to the minimum
+    let x = gradient_descent(f, grad_f, &[0.0, 0.0], 0.05, 500);
+    println!("Gradient descent: ({:.6}, {:.6})", x[0], x[1]);
+    assert!((x[0] - 3.0).abs() < 1e-6 && (x[1] + 2.0).abs() < 1e-6);
+    assert!(f(&x) < 1e-10);
+    // Too large a step overshoots further every time; the best point is
+    // still the starting one, not somewhere out at infinity
+    assert_eq!(
+        gradient_descent(f, grad_f, &[0.0, 0.0], 0.2, 50),
+        vec![0.0, 0.0]
+    );
+
+    // Test Exercise 3: so does Adam
+    let x = adam_optimizer(f, grad_f, &[0.0, 0.0]);
+    println!("Adam:             ({:.6}, {:.6})", x[0], x[1]);
+    assert!((x[0] - 3.0).abs() < 1e-2 && (x[1] + 2.0).abs() < 1e-2);
+
+    // Test Exercise 2: points on y = 1 + 2x - 0.5x^2 are fitted almost
+    // exactly
+    let points: Vec<(f64, f64)> = (-10..=10)
+        .map(|i| {
+            let x = i as f64 / 10.0;
+            (x, 1.0 + 2.0 * x - 0.5 * x * x)
+        })
+        .collect();
+    let poly = fit_polynomial_gd(&points, 2, 0.5, 5_000);
+    let mse = mean_squared_error(&poly, &points);
+    println!(
+        "Fitted coefficients: {:.4?}, MSE = {:.2e}",
+        poly.coefficients, mse
+    );
+    assert!(mse < 1e-10);
+    for (c, expected) in poly.coefficients.iter().zip([1.0, 2.0, -0.5]) {
+        assert!((c - expected).abs() < 1e-4);
+    }
+
+    // A straight line can't fit a parabola: the error stays well above 0
+    let line = fit_polynomial_gd(&points, 1, 0.5, 5_000);
+    assert!(mean_squared_error(&line, &points) > 1e-3);
+}
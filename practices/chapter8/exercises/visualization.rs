@@ -0,0 +1,213 @@
+// Grade Visualization: Render a set of numbers as text, so a grade
+// distribution can be eyeballed right in the terminal. Each chart is built
+// with iterator chains over the data, and every function returns a String
+// instead of printing, which keeps it easy to check.
+
+// Exercise 1 Solution: Histogram
+// Splits [min, max] into equal bins and draws one line per bin. Values
+// outside the range are left out; `max` itself lands in the last bin.
+fn histogram(data: &[f64], bins: usize, min: f64, max: f64) -> String {
+    if bins == 0 || min >= max {
+        return String::new();
+    }
+    let width = (max - min) / bins as f64;
+    let mut counts = vec![0; bins];
+    for &value in data.iter().filter(|v| (min..=max).contains(*v)) {
+        let bin = (((value - min) / width) as usize).min(bins - 1);
+        counts[bin] += 1;
+    }
+
+    counts
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            let low = min + i as f64 * width;
+            format!(
+                "{:>6.1} - {:<6.1} | {} ({})\n",
+                low,
+                low + width,
+                "#".repeat(count),
+                count
+            )
+        })
+        .collect()
+}
+
+// Helper type: just enough of a student for this exercise
+struct Student {
+    name: String,
+    grades: Vec<f64>,
+}
+
+impl Student {
+    fn average(&self) -> Option<f64> {
+        if self.grades.is_empty() {
+            return None;
+        }
+        Some(self.grades.iter().sum::<f64>() / self.grades.len() as f64)
+    }
+}
+
+// One value per student (their average), in ten bins from 0 to 100.
+// Students with no grades yet are skipped.
+fn grade_distribution(students: &[Student]) -> String {
+    let averages: Vec<f64> = students.iter().filter_map(Student::average).collect();
+    histogram(&averages, 10, 0.0, 100.0)
+}
+
+// Exercise 2 Solution: Box plot
+// Quartiles use linear interpolation between the two nearest values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let below = position.floor() as usize;
+    let above = position.ceil() as usize;
+    sorted[below] + (sorted[above] - sorted[below]) * (position - below as f64)
+}
+
+// (min, Q1, median, Q3, max)
+fn five_number_summary(data: &[f64]) -> Option<(f64, f64, f64, f64, f64)> {
+    if data.is_empty() {
+        return None;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    Some((
+        sorted[0],
+        quantile(&sorted, 0.25),
+        quantile(&sorted, 0.5),
+        quantile(&sorted, 0.75),
+        sorted[sorted.len() - 1],
+    ))
+}
+
+// Draws the whiskers with '-', the box between Q1 and Q3 with '[' and ']',
+// and the median with '|', scaled to a fixed width:
+//     -----[====|=======]--------
+const PLOT_WIDTH: usize = 50;
+
+fn box_plot(data: &[f64]) -> String {
+    let Some((min, q1, median, q3, max)) = five_number_summary(data) else {
+        return String::new();
+    };
+    let column = |value: f64| {
+        if max == min {
+            return 0;
+        }
+        ((value - min) / (max - min) * (PLOT_WIDTH - 1) as f64).round() as usize
+    };
+
+    let mut line = ['-'; PLOT_WIDTH];
+    for cell in &mut line[column(q1)..=column(q3)] {
+        *cell = '=';
+    }
+    line[column(q1)] = '[';
+    line[column(q3)] = ']';
+    line[column(median)] = '|';
+
+    format!(
+        "{}\nmin={:.1} Q1={:.1} median={:.1} Q3={:.1} max={:.1}\n",
+        line.iter().collect::<String>(),
+        min,
+        q1,
+        median,
+        q3,
+        max
+    )
+}
+
+// Exercise 3 Solution: Stem-and-leaf plot
+// `precision` is the number of decimal places kept. Each value is scaled
+// to an integer; its last digit is the leaf and the rest is the stem, so
+// with precision 0, 87 is stem 8 and leaf 7.
+fn stem_and_leaf(data: &[f64], precision: u32) -> String {
+    let scale = 10f64.powi(precision as i32);
+    let mut scaled: Vec<i64> = data.iter().map(|v| (v * scale).round() as i64).collect();
+    scaled.sort();
+
+    let mut rows: Vec<(i64, Vec<i64>)> = Vec::new();
+    for value in scaled {
+        let (stem, leaf) = (value.div_euclid(10), value.rem_euclid(10));
+        match rows.last_mut() {
+            Some((last, leaves)) if *last == stem => leaves.push(leaf),
+            _ => rows.push((stem, vec![leaf])),
+        }
+    }
+
+    rows.iter()
+        .map(|(stem, leaves)| {
+            let leaves: Vec<String> = leaves.iter().map(|l| l.to_string()).collect();
+            format!("{:>4} | {}\n", stem, leaves.join(" "))
+        })
+        .collect()
+}
+
+fn main() {
+    let grades = [
+        55.0, 62.0, 68.0, 71.0, 74.0, 75.0, 78.0, 81.0, 83.0, 85.0, 88.0, 92.0, 97.0, 100.0,
+    ];
+
+    // Test Exercise 1: one line per bin, each starting with its label
+    let chart = histogram(&grades, 5, 50.0, 100.0);
+    println!("{}", chart);
+    let lines: Vec<&str> = chart.lines().collect();
+    assert_eq!(lines.len(), 5);
+    for (i, line) in lines.iter().enumerate() {
+        let label = format!(
+            "{:>6.1} - {:<6.1}",
+            50.0 + 10.0 * i as f64,
+            60.0 + 10.0 * i as f64
+        );
+        assert!(line.starts_with(&label));
+    }
+    // 100 lands in the last bin
+    assert!(lines[4].ends_with("### (3)"));
+    assert!(histogram(&[], 3, 0.0, 1.0)
+        .lines()
+        .all(|l| l.ends_with("(0)")));
+
+    let class = [
+        Student {
+            name: "Alice".to_string(),
+            grades: vec![90.0, 94.0],
+        },
+        Student {
+            name: "Bob".to_string(),
+            grades: vec![71.0],
+        },
+        Student {
+            name: "Carol".to_string(),
+            grades: vec![],
+        },
+    ];
+    let distribution = grade_distribution(&class);
+    println!("Class distribution ({} students):", class.len());
+    println!("{}", distribution);
+    assert_eq!(distribution.lines().count(), 10);
+    assert!(distribution.lines().nth(9).unwrap().ends_with("# (1)"));
+    assert!(class[2].average().is_none());
+    println!("{} has no grades yet", class[2].name);
+
+    // Test Exercise 2: the median marker sits inside the box
+    let plot = box_plot(&grades);
+    println!("{}", plot);
+    let drawing = plot.lines().next().unwrap();
+    let q1 = drawing.find('[').unwrap();
+    let q3 = drawing.find(']').unwrap();
+    let median = drawing.find('|').unwrap();
+    assert!(q1 <= median && median <= q3);
+    assert_eq!(drawing.chars().count(), PLOT_WIDTH);
+
+    let (_, q1, median, q3, _) = five_number_summary(&grades).unwrap();
+    assert!(q1 <= median && median <= q3);
+    assert_eq!(box_plot(&[]), "");
+
+    // Test Exercise 3: stems and leaves both come out sorted
+    let plot = stem_and_leaf(&[88.0, 71.0, 85.0, 92.0, 74.0, 100.0, 78.0], 0);
+    println!("{}", plot);
+    assert_eq!(plot, "   7 | 1 4 8\n   8 | 5 8\n   9 | 2\n  10 | 0\n");
+    assert_eq!(
+        stem_and_leaf(&[87.5, 87.25, 86.0], 1),
+        "  86 | 0\n  87 | 3 5\n"
+    );
+    assert_eq!(stem_and_leaf(&[], 0), "");
+}
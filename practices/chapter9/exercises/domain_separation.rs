@@ -0,0 +1,129 @@
+// Domain Separation: Give every protocol that uses a hash function its own
+// namespace, so a hash computed for one protocol can never be passed off
+// as a hash from another. Without it, a Schnorr challenge and a Pedersen
+// generator derived from the same bytes would be the same value, and an
+// attacker who can get one signed or committed could reuse it in the other.
+//
+// The label has to be length-prefixed. If we just hashed label || input,
+// then ("ab", "c") and ("a", "bc") would hash the same bytes.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+// Helper function standing in for SHA-256. DefaultHasher is NOT a
+// cryptographic hash; `write` feeds it the raw bytes, without the length
+// that `Hash for [u8]` would add, so the framing below is all ours.
+fn hash_bytes(data: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    for (i, chunk) in output.chunks_mut(8).enumerate() {
+        let mut hasher = DefaultHasher::new();
+        hasher.write_u8(i as u8);
+        hasher.write(data);
+        chunk.copy_from_slice(&hasher.finish().to_be_bytes());
+    }
+    output
+}
+
+// What not to do: plain concatenation
+fn naive_hash(label: &[u8], input: &[u8]) -> [u8; 32] {
+    hash_bytes(&[label, input].concat())
+}
+
+// Exercise 1 Solution: Separating domains
+// The label's length goes in front as 8 big-endian bytes, then the label,
+// then the input. Reading from the left, the length says exactly where the
+// label ends, so no two (label, input) pairs give the same bytes.
+fn domain_separate(label: &[u8], input: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(8 + label.len() + input.len());
+    framed.extend_from_slice(&(label.len() as u64).to_be_bytes());
+    framed.extend_from_slice(label);
+    framed.extend_from_slice(input);
+    hash_bytes(&framed).to_vec()
+}
+
+// Exercise 2 Solution: A hasher bound to one label
+struct DomainSeparatedHasher {
+    label: Vec<u8>,
+}
+
+impl DomainSeparatedHasher {
+    fn new(label: &[u8]) -> Self {
+        DomainSeparatedHasher {
+            label: label.to_vec(),
+        }
+    }
+
+    fn hash(&self, input: &[u8]) -> [u8; 32] {
+        let digest = domain_separate(&self.label, input);
+        digest.try_into().unwrap()
+    }
+}
+
+fn create_hashers(labels: &[&[u8]]) -> Vec<DomainSeparatedHasher> {
+    labels
+        .iter()
+        .map(|label| DomainSeparatedHasher::new(label))
+        .collect()
+}
+
+// Exercise 3 Solution: Hashing into a field
+// Helper type: a field element modulo the Mersenne prime 2^61 - 1. It
+// stands in for the circuit's scalar field.
+const MODULUS: u64 = (1 << 61) - 1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FieldElement(u64);
+
+impl FieldElement {
+    fn new(value: u64) -> Self {
+        FieldElement(value % MODULUS)
+    }
+}
+
+// Hash the label and the element's bytes, then reduce the first 8 bytes
+// of the digest into the field. This is how a circuit derives challenges
+// from earlier values without mixing up different uses.
+fn domain_separate_field(label: &[u8], value: FieldElement) -> FieldElement {
+    let digest = domain_separate(label, &value.0.to_be_bytes());
+    FieldElement::new(u64::from_be_bytes(digest[..8].try_into().unwrap()))
+}
+
+fn main() {
+    let x = b"the same input bytes";
+
+    // Test Exercise 2: the same input hashes differently in each domain
+    let schnorr = DomainSeparatedHasher::new(b"schnorr");
+    let pedersen = DomainSeparatedHasher::new(b"pedersen");
+    assert_ne!(schnorr.hash(x), pedersen.hash(x));
+    assert_eq!(
+        schnorr.hash(x),
+        DomainSeparatedHasher::new(b"schnorr").hash(x)
+    );
+    println!("schnorr:  {:02x?}", &schnorr.hash(x)[..8]);
+    println!("pedersen: {:02x?}", &pedersen.hash(x)[..8]);
+
+    // Test Exercise 1: concatenation is ambiguous, the length prefix isn't
+    assert_eq!(naive_hash(b"ab", b"c"), naive_hash(b"a", b"bc"));
+    assert_ne!(domain_separate(b"ab", b"c"), domain_separate(b"a", b"bc"));
+    assert_eq!(domain_separate(b"schnorr", x), schnorr.hash(x).to_vec());
+    assert_eq!(domain_separate(b"", x).len(), 32);
+
+    // Every hasher from create_hashers lives in its own domain
+    let labels: [&[u8]; 4] = [b"transcript", b"commitment", b"challenge", b"nullifier"];
+    let hashers = create_hashers(&labels);
+    assert_eq!(hashers.len(), labels.len());
+    let digests: Vec<[u8; 32]> = hashers.iter().map(|h| h.hash(x)).collect();
+    for (i, a) in digests.iter().enumerate() {
+        for b in &digests[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+
+    // Test Exercise 3: field hashing is deterministic and separated too
+    let value = FieldElement::new(42);
+    let challenge = domain_separate_field(b"challenge", value);
+    assert_eq!(challenge, domain_separate_field(b"challenge", value));
+    assert_ne!(challenge, domain_separate_field(b"nullifier", value));
+    assert!(challenge.0 < MODULUS);
+    println!("challenge for 42: {:?}", challenge);
+}
@@ -0,0 +1,179 @@
+// Parallel Merkle Tree: Build a Merkle root by hashing each level of the
+// tree across several worker threads. The main thread hands out leaf pairs
+// over std::sync::mpsc channels, and the workers write their results into
+// shared state guarded by Arc<Mutex<...>>. No external crates are needed.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+// Helper function standing in for SHA-256. DefaultHasher is NOT a
+// cryptographic hash, but it is deterministic and lets us focus on the
+// threading part of the exercise.
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    for (i, chunk) in output.chunks_mut(8).enumerate() {
+        let mut hasher = DefaultHasher::new();
+        i.hash(&mut hasher);
+        left.hash(&mut hasher);
+        right.hash(&mut hasher);
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    output
+}
+
+// Exercise 1 Solution: Sequential Merkle root
+// An odd node at the end of a level is paired with itself.
+fn sequential_merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+    }
+    level[0]
+}
+
+// Exercise 2 Solution: Shared tree state
+struct TreeBuilder {
+    level: Arc<Mutex<Vec<[u8; 32]>>>,
+}
+
+impl TreeBuilder {
+    fn new(leaves: &[[u8; 32]]) -> Self {
+        TreeBuilder {
+            level: Arc::new(Mutex::new(leaves.to_vec())),
+        }
+    }
+
+    fn level_len(&self) -> usize {
+        self.level.lock().unwrap().len()
+    }
+
+    // Hash the current level into the next one. The pairs are sent
+    // round-robin to `n_threads` workers, and each worker writes its
+    // hashes into the next level at the pair's index.
+    fn build_next_level(&self, n_threads: usize) {
+        let current = self.level.lock().unwrap().clone();
+        let n_pairs = current.len().div_ceil(2);
+        let next = Arc::new(Mutex::new(vec![[0u8; 32]; n_pairs]));
+
+        thread::scope(|scope| {
+            let mut senders = Vec::new();
+
+            for _ in 0..n_threads.max(1) {
+                let (tx, rx) = mpsc::channel::<(usize, [u8; 32], [u8; 32])>();
+                let next = Arc::clone(&next);
+                senders.push(tx);
+
+                scope.spawn(move || {
+                    // The loop ends once the sender is dropped
+                    for (index, left, right) in rx {
+                        let hash = hash_pair(&left, &right);
+                        next.lock().unwrap()[index] = hash;
+                    }
+                });
+            }
+
+            for (index, pair) in current.chunks(2).enumerate() {
+                let right = *pair.get(1).unwrap_or(&pair[0]);
+                senders[index % senders.len()]
+                    .send((index, pair[0], right))
+                    .unwrap();
+            }
+            // Dropping the senders lets every worker finish its loop
+            drop(senders);
+        });
+
+        let next = next.lock().unwrap().clone();
+        *self.level.lock().unwrap() = next;
+    }
+
+    fn root(&self) -> Option<[u8; 32]> {
+        let level = self.level.lock().unwrap();
+        if level.len() == 1 {
+            Some(level[0])
+        } else {
+            None
+        }
+    }
+}
+
+// Exercise 3 Solution: Parallel Merkle root
+fn parallel_merkle_root(leaves: &[[u8; 32]], n_threads: usize) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let builder = TreeBuilder::new(leaves);
+    while builder.level_len() > 1 {
+        builder.build_next_level(n_threads);
+    }
+    builder.root().unwrap()
+}
+
+fn make_leaves(n: usize) -> Vec<[u8; 32]> {
+    (0..n)
+        .map(|i| {
+            let mut leaf = [0u8; 32];
+            leaf[..8].copy_from_slice(&(i as u64).to_le_bytes());
+            leaf
+        })
+        .collect()
+}
+
+fn main() {
+    // Test Exercise 1 and 3: both approaches agree
+    for n in [3, 4, 7, 16, 100] {
+        let leaves = make_leaves(n);
+        assert_eq!(
+            parallel_merkle_root(&leaves, 4),
+            sequential_merkle_root(&leaves)
+        );
+    }
+
+    // Edge case: a single leaf is its own root
+    let single = make_leaves(1);
+    assert_eq!(parallel_merkle_root(&single, 4), single[0]);
+    assert_eq!(sequential_merkle_root(&single), single[0]);
+
+    // Edge case: two leaves hash into the root directly
+    let two = make_leaves(2);
+    assert_eq!(parallel_merkle_root(&two, 4), hash_pair(&two[0], &two[1]));
+    assert_eq!(sequential_merkle_root(&two), hash_pair(&two[0], &two[1]));
+
+    // Test Exercise 2: one level halves the number of nodes
+    let builder = TreeBuilder::new(&make_leaves(8));
+    builder.build_next_level(2);
+    assert_eq!(builder.level_len(), 4);
+    assert!(builder.root().is_none());
+
+    // Measure the speedup for 1024 leaves
+    let leaves = make_leaves(1024);
+
+    let start = Instant::now();
+    let sequential_root = sequential_merkle_root(&leaves);
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel_root = parallel_merkle_root(&leaves, 4);
+    let parallel_time = start.elapsed();
+
+    assert_eq!(sequential_root, parallel_root);
+    println!("Sequential: {:?}", sequential_time);
+    println!("Parallel (4 threads): {:?}", parallel_time);
+    // With a hash this cheap, spawning threads often costs more than it
+    // saves. Try a slower hash to see the parallel version pull ahead.
+    println!(
+        "Speedup: {:.2}x",
+        sequential_time.as_secs_f64() / parallel_time.as_secs_f64()
+    );
+}
@@ -0,0 +1,106 @@
+// Proof of Work: Find a nonce so that hash(challenge || nonce) starts with
+// a given number of zero bits. There is no shortcut: each extra bit of
+// difficulty doubles the expected number of hashes, so the work grows as
+// O(2^difficulty).
+//
+// Why halo2 doesn't work like this: a PoW only shows that *some* work was
+// spent, by brute force. A ZK proof shows that a specific statement is
+// true. The prover evaluates the circuit once and commits to polynomials
+// with FFTs and multi-scalar multiplications, which is O(n log n) in the
+// circuit size n and involves no guessing at all. Making the statement
+// harder to prove means a larger circuit, not exponentially more tries.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+// Helper function standing in for SHA-256. DefaultHasher is NOT a
+// cryptographic hash, but it is deterministic and its output bits are
+// spread out well enough to show how the search behaves.
+fn hash_with_nonce(challenge: &[u8], nonce: u64) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    for (i, chunk) in output.chunks_mut(8).enumerate() {
+        let mut hasher = DefaultHasher::new();
+        i.hash(&mut hasher);
+        challenge.hash(&mut hasher);
+        nonce.to_be_bytes().hash(&mut hasher);
+        chunk.copy_from_slice(&hasher.finish().to_be_bytes());
+    }
+    output
+}
+
+fn leading_zero_bits(digest: &[u8; 32]) -> u32 {
+    let mut zeros = 0;
+    for &byte in digest {
+        zeros += byte.leading_zeros();
+        if byte != 0 {
+            break;
+        }
+    }
+    zeros
+}
+
+// Exercise 1 Solution: Searching for a nonce
+fn compute_pow(challenge: &[u8], difficulty: u8) -> u64 {
+    (0..)
+        .find(|&nonce| verify_pow(challenge, nonce, difficulty))
+        .unwrap()
+}
+
+// Exercise 2 Solution: Verifying is a single hash
+// This asymmetry (slow to find, instant to check) is what makes PoW useful.
+fn verify_pow(challenge: &[u8], nonce: u64, difficulty: u8) -> bool {
+    leading_zero_bits(&hash_with_nonce(challenge, nonce)) >= difficulty as u32
+}
+
+// Exercise 3 Solution: Expected work
+// Each hash has a 1 / 2^difficulty chance of starting with enough zeros,
+// so on average we need 2^difficulty tries.
+fn estimate_expected_iterations(difficulty: u8) -> f64 {
+    2f64.powi(difficulty as i32)
+}
+
+fn main() {
+    // Test Exercise 1 and 2
+    let challenge = b"halo2 block header";
+    for difficulty in [0, 4, 8] {
+        let nonce = compute_pow(challenge, difficulty);
+        println!("difficulty {:2}: nonce {}", difficulty, nonce);
+        assert!(verify_pow(challenge, nonce, difficulty));
+        // The first valid nonce really is the first one
+        assert!((0..nonce).all(|n| !verify_pow(challenge, n, difficulty)));
+    }
+    assert_eq!(compute_pow(challenge, 0), 0);
+
+    // Test Exercise 3: doubling per bit
+    assert_eq!(estimate_expected_iterations(0), 1.0);
+    assert_eq!(estimate_expected_iterations(10), 1024.0);
+    assert_eq!(
+        estimate_expected_iterations(11),
+        2.0 * estimate_expected_iterations(10)
+    );
+
+    // Averaged over many challenges, the search takes about 2^difficulty tries
+    let difficulty = 6;
+    let n_challenges = 200;
+    let total: u64 = (0..n_challenges)
+        .map(|i: u32| compute_pow(&i.to_be_bytes(), difficulty) + 1)
+        .sum();
+    let average = total as f64 / n_challenges as f64;
+    println!(
+        "difficulty {}: {:.1} tries on average, {} expected",
+        difficulty,
+        average,
+        estimate_expected_iterations(difficulty)
+    );
+    let expected = estimate_expected_iterations(difficulty);
+    assert!(average > expected / 2.0 && average < expected * 2.0);
+
+    // Timing test: difficulty 10 needs about a thousand hashes
+    let start = Instant::now();
+    let nonce = compute_pow(challenge, 10);
+    let elapsed = start.elapsed();
+    println!("difficulty 10: nonce {} found in {:?}", nonce, elapsed);
+    assert!(verify_pow(challenge, nonce, 10));
+    assert!(elapsed < Duration::from_secs(60));
+}